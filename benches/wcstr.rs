@@ -0,0 +1,88 @@
+#![feature(test)]
+
+extern crate test;
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use test::{Bencher, black_box};
+use thicc::{WCString, WChar, Wide};
+
+const LEN: usize = 100_000;
+
+fn long_string() -> WCString<u16> {
+    let mut s = WCString::new();
+    s.push_str(&"a".repeat(LEN));
+    s
+}
+
+#[bench]
+fn eq_long(b: &mut Bencher) {
+    let x = long_string();
+    let y = long_string();
+
+    b.iter(|| black_box(x.as_wcstr()) == black_box(y.as_wcstr()));
+}
+
+#[bench]
+fn eq_long_differ_first(b: &mut Bencher) {
+    let x = long_string();
+    let mut y = WCString::<u16>::new();
+    y.push_str("b");
+    y.push_str(&"a".repeat(LEN - 1));
+
+    b.iter(|| black_box(x.as_wcstr()) == black_box(y.as_wcstr()));
+}
+
+#[bench]
+fn hash_long(b: &mut Bencher) {
+    let x = long_string();
+
+    b.iter(|| {
+        let mut state = DefaultHasher::new();
+        black_box(x.as_wcstr()).hash(&mut state);
+        state.finish()
+    });
+}
+
+#[bench]
+fn len_long(b: &mut Bencher) {
+    let x = long_string();
+
+    b.iter(|| black_box(x.as_wcstr()).len());
+}
+
+fn string_of<T: Wide>(len: usize) -> WCString<T> {
+    let mut s = WCString::new();
+    s.push_str(&"a".repeat(len));
+    s
+}
+
+macro_rules! bench_len {
+    ($($name:ident: $ty:ty, $len:expr;)*) => {
+        $(
+            #[bench]
+            fn $name(b: &mut Bencher) {
+                let x = string_of::<$ty>($len);
+
+                b.iter(|| black_box(x.as_wcstr()).len());
+            }
+        )*
+    };
+}
+
+// `WChar` uses `libc::wcslen`, and `u16` and `u32` use the generic fallback.
+bench_len! {
+    len_tiny_wchar: WChar, 3;
+    len_tiny_u32: u32, 3;
+    len_tiny_u16: u16, 3;
+    len_short_wchar: WChar, 8;
+    len_short_u32: u32, 8;
+    len_short_u16: u16, 8;
+    len_medium_wchar: WChar, 64;
+    len_medium_u32: u32, 64;
+    len_medium_u16: u16, 64;
+    len_empty_wchar: WChar, 0;
+    len_empty_u32: u32, 0;
+    len_empty_u16: u16, 0;
+}
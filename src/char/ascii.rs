@@ -0,0 +1,38 @@
+pub trait Ascii: Copy + Eq + 'static {
+    fn is_ascii(self) -> bool;
+    fn to_ascii_lowercase(self) -> Self;
+    fn to_ascii_uppercase(self) -> Self;
+}
+
+macro_rules! impl_ascii {
+    ($($ty:ident)*) => {
+        $(
+            impl Ascii for $ty {
+                #[inline]
+                fn is_ascii(self) -> bool {
+                    // Negative units of signed backings are not ASCII.
+                    (0x00..=0x7F).contains(&self)
+                }
+
+                #[inline]
+                fn to_ascii_lowercase(self) -> Self {
+                    // Surrogates and all other non-ASCII units lie outside this
+                    // range, so are never folded.
+                    match self {
+                        0x41..=0x5A => self + 0x20,
+                        _ => self,
+                    }
+                }
+
+                #[inline]
+                fn to_ascii_uppercase(self) -> Self {
+                    match self {
+                        0x61..=0x7A => self - 0x20,
+                        _ => self,
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_ascii!(u16 u32 i16 i32);
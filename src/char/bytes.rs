@@ -0,0 +1,31 @@
+use core::convert::TryInto;
+
+pub trait Bytes: Copy + 'static {
+    fn from_le_bytes(bytes: &[u8]) -> Self;
+    fn from_be_bytes(bytes: &[u8]) -> Self;
+    fn swap_bytes(self) -> Self;
+}
+
+macro_rules! impl_bytes {
+    ($($ty:ident)*) => {
+        $(
+            impl Bytes for $ty {
+                #[inline]
+                fn from_le_bytes(bytes: &[u8]) -> Self {
+                    $ty::from_le_bytes(bytes.try_into().unwrap())
+                }
+
+                #[inline]
+                fn from_be_bytes(bytes: &[u8]) -> Self {
+                    $ty::from_be_bytes(bytes.try_into().unwrap())
+                }
+
+                #[inline]
+                fn swap_bytes(self) -> Self {
+                    $ty::swap_bytes(self)
+                }
+            }
+        )*
+    };
+}
+impl_bytes!(u16 u32 i16 i32);
@@ -1,7 +1,9 @@
+use core::cell::Cell;
 use core::fmt;
 use core::iter::FusedIterator;
 use core::marker::PhantomData;
 use core::mem;
+use core::ptr;
 
 use crate::char::Wide;
 
@@ -13,24 +15,25 @@ pub struct DecodeWideError<T> {
 pub trait Decode: Copy + Eq + Ord + 'static {
     fn next(iter: &mut Chars<'_, Self>) -> Option<Result<char, DecodeWideError<Self>>>;
     fn size_hint(wcslen: usize) -> (usize, Option<usize>);
+    fn count(iter: Chars<'_, Self>) -> usize;
+    fn last(iter: Chars<'_, Self>) -> Option<Result<char, DecodeWideError<Self>>>;
+    /// Returns `true` if `index` does not fall between the units of an
+    /// encoded `char`. `index` must be at most `units.len()`.
+    fn is_char_boundary(units: &[Self], index: usize) -> bool;
+}
+
+pub trait Encode: Copy + 'static {
+    fn encode(c: char, buf: &mut [Self; 2]) -> &[Self];
 }
 
 pub struct Chars<'a, T> {
     ptr: *const T,
+    // The position of the NUL-terminator, once it has been found. This is null
+    // until then, so that iterating never requires finding the length.
+    end: Cell<*const T>,
     _marker: PhantomData<&'a T>,
 }
 
-macro_rules! wcslen {
-    ($self:ident) => {{
-        // Sometimes used within an unsafe block.
-        #![allow(unused_unsafe)]
-
-        // SAFETY: Safe references can only exist if they point to memory that
-        //         has a NUL-terminator.
-        unsafe { $crate::char::SpecLen::wcslen($self.ptr) }
-    }};
-}
-
 macro_rules! impl_decode_utf16 {
     ($($ty:ident)*) => {
         $(
@@ -80,6 +83,47 @@ macro_rules! impl_decode_utf16 {
                     // or entirely non-surrogates (1 element per char).
                     (wcslen / 2, Some(wcslen))
                 }
+
+                #[inline]
+                fn count(mut iter: Chars<'_, Self>) -> usize {
+                    let mut n = 0;
+                    while Self::next(&mut iter).is_some() {
+                        n += 1;
+                    }
+                    n
+                }
+
+                #[inline]
+                fn last(mut iter: Chars<'_, Self>) -> Option<Result<char, DecodeWideError<$ty>>> {
+                    let mut last = None;
+                    while let Some(r) = Self::next(&mut iter) {
+                        last = Some(r);
+                    }
+                    last
+                }
+
+                #[inline]
+                fn is_char_boundary(units: &[Self], index: usize) -> bool {
+                    if index == 0 || index >= units.len() {
+                        return true;
+                    }
+                    // SAFETY: `$ty` has the same size and alignment as `u16`.
+                    let units = unsafe { &*(units as *const [$ty] as *const [u16]) };
+                    // Only a leading surrogate followed by a trailing surrogate is
+                    // decoded as a single `char`.
+                    let leading = (0xD800..0xDC00).contains(&units[index - 1]);
+                    let trailing = (0xDC00..0xE000).contains(&units[index]);
+                    !(leading && trailing)
+                }
+            }
+
+            impl Encode for $ty {
+                #[inline]
+                fn encode(c: char, buf: &mut [Self; 2]) -> &[Self] {
+                    // SAFETY: `$ty` has the same size and alignment as `u16`.
+                    let len = c.encode_utf16(unsafe { &mut *(buf as *mut [$ty; 2] as *mut [u16; 2]) }).len();
+                    &buf[..len]
+                }
             }
         )*
     };
@@ -115,12 +159,45 @@ macro_rules! impl_decode_utf32 {
                 fn size_hint(wcslen: usize) -> (usize, Option<usize>) {
                     (wcslen, Some(wcslen))
                 }
+
+                #[inline]
+                fn count(iter: Chars<'_, Self>) -> usize {
+                    // Every code unit decodes to exactly one item.
+                    iter.remaining()
+                }
+
+                #[inline]
+                fn last(mut iter: Chars<'_, Self>) -> Option<Result<char, DecodeWideError<$ty>>> {
+                    // Every code unit decodes to exactly one item, so skip
+                    // straight to the last one.
+                    let n = iter.remaining();
+                    if n == 0 {
+                        return None;
+                    }
+                    // SAFETY: There are `n` wide characters before the
+                    //         NUL-terminator.
+                    iter.ptr = unsafe { iter.ptr.add(n - 1) };
+                    Self::next(&mut iter)
+                }
+
+                #[inline]
+                fn is_char_boundary(_units: &[Self], _index: usize) -> bool {
+                    true
+                }
+            }
+
+            impl Encode for $ty {
+                #[inline]
+                fn encode(c: char, buf: &mut [Self; 2]) -> &[Self] {
+                    buf[0] = c as u32 as $ty;
+                    &buf[..1]
+                }
             }
 
             impl ExactSizeIterator for Chars<'_, $ty> {
                 #[inline(always)]
                 fn len(&self) -> usize {
-                    wcslen!(self)
+                    self.remaining()
                 }
             }
         )*
@@ -133,11 +210,49 @@ impl<'a, T: Wide> Chars<'a, T> {
     pub(crate) fn new(ptr: *const T) -> Chars<'a, T> {
         Chars {
             ptr,
+            end: Cell::new(ptr::null()),
             _marker: PhantomData,
         }
     }
+
+    /// Returns the number of wide characters remaining before the
+    /// NUL-terminator, finding it only on the first call.
+    #[inline]
+    fn remaining(&self) -> usize {
+        use crate::char::SpecLen;
+
+        let mut end = self.end.get();
+        if end.is_null() {
+            // SAFETY: Safe references to `Chars` can only exist if they point to
+            //         memory that has a NUL-terminator.
+            end = unsafe { self.ptr.add(SpecLen::wcslen(self.ptr)) };
+            self.end.set(end);
+        }
+        // SAFETY: `ptr` is never advanced past the NUL-terminator at `end`.
+        unsafe { end.offset_from(self.ptr) as usize }
+    }
 }
 
+/// Decodes the wide characters of a string into [`char`]s.
+///
+/// For UTF-32 backings every wide character decodes to exactly one item, so
+/// [`Iterator::count`] and [`Iterator::last`] only need to find the
+/// NUL-terminator, rather than decoding every item.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use thicc::wcstr;
+///
+/// assert_eq!(wcstr!(u32, "a🦀b").chars().count(), 3);
+/// assert_eq!(wcstr!(u32, "a🦀b").chars().last(), Some(Ok('b')));
+///
+/// assert_eq!(wcstr!(u16, "a🦀").chars().count(), 2);
+/// assert_eq!(wcstr!(u16, "a🦀").chars().last(), Some(Ok('🦀')));
+/// assert_eq!(wcstr!(u16, "").chars().last(), None);
+/// ```
 impl<'a, T: Wide> Iterator for Chars<'a, T> {
     type Item = Result<char, DecodeWideError<T>>;
 
@@ -147,13 +262,74 @@ impl<'a, T: Wide> Iterator for Chars<'a, T> {
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        T::size_hint(wcslen!(self))
+        T::size_hint(self.remaining())
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        T::count(self)
+    }
+
+    #[inline]
+    fn last(self) -> Option<Self::Item> {
+        T::last(self)
     }
 }
 
 impl<T: Wide> FusedIterator for Chars<'_, T> {}
 
+/// Clones the iterator at its current position.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use thicc::wcstr;
+///
+/// let mut iter = wcstr!(u16, "a🦀b").chars();
+/// assert_eq!(iter.next(), Some(Ok('a')));
+///
+/// let mut peek = iter.clone();
+/// assert_eq!(peek.next(), Some(Ok('🦀')));
+/// assert_eq!(peek.next(), Some(Ok('b')));
+/// assert_eq!(peek.next(), None);
+///
+/// assert_eq!(iter.next(), Some(Ok('🦀')));
+/// assert_eq!(iter.next(), Some(Ok('b')));
+/// assert_eq!(iter.next(), None);
+/// ```
+impl<T> Clone for Chars<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Chars {
+            ptr: self.ptr,
+            end: self.end.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
 impl<T: Wide> DecodeWideError<T> {
+    /// Creates a new `DecodeWideError` for the wide character that could not
+    /// be decoded.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::DecodeWideError;
+    ///
+    /// let err = DecodeWideError::new(0xD800u16);
+    ///
+    /// assert_eq!(err.code(), 0xD800);
+    /// ```
+    #[inline]
+    pub const fn new(code: T) -> DecodeWideError<T> {
+        DecodeWideError { code }
+    }
+
     /// Returns the wide character that caused this error.
     #[inline]
     pub fn code(&self) -> T {
@@ -161,6 +337,28 @@ impl<T: Wide> DecodeWideError<T> {
     }
 }
 
+/// Compares the wide character that caused the error with a raw value.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use thicc::WCStr;
+///
+/// // <invalid><nul>
+/// let v: &[u16] = &[0xD800, 0x0000];
+/// let s = WCStr::from_slice_with_nul(v).unwrap();
+///
+/// assert_eq!(s.chars().next().unwrap().unwrap_err(), 0xD800u16);
+/// ```
+impl<T: Wide> PartialEq<T> for DecodeWideError<T> {
+    #[inline]
+    fn eq(&self, other: &T) -> bool {
+        self.code == *other
+    }
+}
+
 impl<T: Wide> fmt::Display for DecodeWideError<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "failed to decode wide character: {:x}", self.code)
@@ -1,12 +1,63 @@
 use core::fmt;
+use core::hash::Hash;
+use core::mem;
 
+mod ascii;
+mod bytes;
 mod convert;
 
+use crate::wcstr::WCStr;
+
 pub use self::convert::{Chars, DecodeWideError};
 
 /// A system wide character, `wchar_t`.
 pub type WChar = libc::wchar_t;
 
+/// The size of a system wide character, [`WChar`], in bytes.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use thicc::{WChar, WCHAR_SIZE};
+///
+/// assert_eq!(WCHAR_SIZE, std::mem::size_of::<WChar>());
+/// ```
+pub const WCHAR_SIZE: usize = mem::size_of::<WChar>();
+
+/// Whether a system wide character, [`WChar`], is a UTF-16 code unit, such as
+/// on Windows.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use thicc::{WCHAR_IS_UTF16, WCHAR_IS_UTF32};
+///
+/// assert_ne!(WCHAR_IS_UTF16, WCHAR_IS_UTF32);
+/// assert_eq!(WCHAR_IS_UTF16, cfg!(windows));
+/// ```
+pub const WCHAR_IS_UTF16: bool = WCHAR_SIZE == 2;
+
+/// Whether a system wide character, [`WChar`], is a UTF-32 code unit, such as
+/// on most Unix platforms.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use thicc::{WCHAR_IS_UTF16, WCHAR_IS_UTF32};
+///
+/// assert_ne!(WCHAR_IS_UTF16, WCHAR_IS_UTF32);
+/// assert_eq!(WCHAR_IS_UTF32, !cfg!(windows));
+/// ```
+pub const WCHAR_IS_UTF32: bool = WCHAR_SIZE == 4;
+
+static_assert!(WCHAR_IS_UTF16 != WCHAR_IS_UTF32);
+
 mod private {
     pub trait Sealed {}
 }
@@ -17,13 +68,17 @@ pub trait Wide:
     + Copy
     + Eq
     + Ord
+    + Hash
     + fmt::Display
     + fmt::Debug
     + fmt::Binary
     + fmt::LowerHex
     + fmt::UpperHex
     + fmt::Octal
+    + ascii::Ascii
+    + bytes::Bytes
     + convert::Decode
+    + convert::Encode
     + wmemchr::Wide
     + 'static
 {
@@ -47,6 +102,13 @@ impl_wide!(u16 u32 i16 i32);
 assert_impls!(WChar: Wide);
 
 pub(crate) trait SpecLen: Wide {
+    /// Finds the number of wide characters before the NUL-terminator.
+    ///
+    /// # Safety
+    ///
+    /// `buf` must point to a NUL-terminated string. Implementations must not
+    /// read past the NUL-terminator, since it may be the last wide character
+    /// of its allocation.
     unsafe fn wcslen(buf: *const Self) -> usize;
 }
 
@@ -54,9 +116,22 @@ pub(crate) trait SpecFind: Wide {
     fn wmemchr(needle: Self, haystack: &[Self]) -> Option<usize>;
 }
 
+pub(crate) trait SpecNth: Wide {
+    fn nth_char(s: &WCStr<Self>, n: usize) -> Option<Result<char, DecodeWideError<Self>>>;
+}
+
 impl<T: Wide> SpecLen for T {
     default unsafe fn wcslen(buf: *const Self) -> usize {
-        let mut end = buf;
+        // Check the first few wide characters with simple branches, since many
+        // strings are short.
+        const SHORT: usize = 4;
+        for i in 0..SHORT {
+            if *buf.add(i) == T::NUL {
+                return i;
+            }
+        }
+
+        let mut end = buf.add(SHORT);
         while *end != T::NUL {
             end = end.add(1);
         }
@@ -71,6 +146,35 @@ impl<T: Wide> SpecFind for T {
     }
 }
 
+impl<T: Wide> SpecNth for T {
+    #[inline]
+    default fn nth_char(s: &WCStr<Self>, n: usize) -> Option<Result<char, DecodeWideError<Self>>> {
+        s.chars().nth(n)
+    }
+}
+
+macro_rules! impl_spec_nth_utf32 {
+    ($($ty:ident)*) => {
+        $(
+            impl SpecNth for $ty {
+                #[inline]
+                fn nth_char(s: &WCStr<Self>, n: usize) -> Option<Result<char, DecodeWideError<Self>>> {
+                    // Every code unit decodes to exactly one item, so only the
+                    // nth code unit needs to be decoded.
+                    if s.checked_len(n).is_some() {
+                        return None;
+                    }
+                    // SAFETY: There are more than `n` wide characters before the
+                    //         NUL-terminator, so the suffix starting at `n` is a
+                    //         valid `WCStr`.
+                    unsafe { WCStr::from_ptr(s.as_ptr().add(n)) }.first_char()
+                }
+            }
+        )*
+    };
+}
+impl_spec_nth_utf32!(u32 i32);
+
 impl SpecLen for WChar {
     #[inline]
     unsafe fn wcslen(buf: *const Self) -> usize {
@@ -0,0 +1,103 @@
+use core::fmt::{self, Write};
+
+use crate::char::Wide;
+use crate::wcstr::WCStr;
+
+/// Helper struct for safely printing a [`WCStr`] with [`format!`] and `{}`.
+///
+/// Invalid data is replaced with
+/// [the replacement character (`U+FFFD`)][U+FFFD].
+///
+/// This struct is created by the [`WCStr::display`] method.
+/// See its documentation for more.
+///
+/// [U+FFFD]: char::REPLACEMENT_CHARACTER
+pub struct Display<'a, T: Wide> {
+    s: &'a WCStr<T>,
+}
+
+/// Helper struct for printing a [`WCStr`] with [`format!`] and `{}`, with
+/// characters escaped as by [`char::escape_debug`].
+///
+/// Invalid data is rendered as a `\u{...}` escape of the raw wide character.
+///
+/// This struct is created by the [`WCStr::display_escaped`] method.
+/// See its documentation for more.
+pub struct DisplayEscaped<'a, T: Wide> {
+    s: &'a WCStr<T>,
+}
+
+impl<'a, T: Wide> Display<'a, T> {
+    #[inline]
+    pub(crate) fn new(s: &'a WCStr<T>) -> Display<'a, T> {
+        Display { s }
+    }
+}
+
+impl<'a, T: Wide> DisplayEscaped<'a, T> {
+    #[inline]
+    pub(crate) fn new(s: &'a WCStr<T>) -> DisplayEscaped<'a, T> {
+        DisplayEscaped { s }
+    }
+}
+
+impl<T: Wide> fmt::Display for Display<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.width().is_none() && f.precision().is_none() {
+            return self.s.write_utf8_lossy(f);
+        }
+
+        // `Formatter::pad` requires a `&str`, so pad the streamed output by
+        // hand rather than buffering the decoded string.
+        let len = self.s.char_count();
+        let len = f.precision().map_or(len, |p| p.min(len));
+        let pad = f.width().unwrap_or(0).saturating_sub(len);
+        let (pre, post) = match f.align() {
+            None | Some(fmt::Alignment::Left) => (0, pad),
+            Some(fmt::Alignment::Right) => (pad, 0),
+            Some(fmt::Alignment::Center) => (pad / 2, (pad + 1) / 2),
+        };
+
+        let fill = f.fill();
+        for _ in 0..pre {
+            f.write_char(fill)?;
+        }
+        for r in self.s.chars().take(len) {
+            f.write_char(r.unwrap_or(char::REPLACEMENT_CHARACTER))?;
+        }
+        for _ in 0..post {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Wide> fmt::Debug for Display<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", DisplayEscaped::new(self.s))
+    }
+}
+
+impl<T: Wide> fmt::Display for DisplayEscaped<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for r in self.s.chars() {
+            match r {
+                Ok(c) => {
+                    for e in c.escape_debug() {
+                        f.write_char(e)?;
+                    }
+                }
+                Err(e) => write!(f, "\\u{{{:x}}}", e.code())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Wide> fmt::Debug for DisplayEscaped<'_, T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self)
+    }
+}
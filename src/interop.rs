@@ -0,0 +1,141 @@
+//! Conversions between thicc strings and the C string types of the
+//! [`widestring`] crate.
+//!
+//! Both crates guarantee that their C strings are NUL-terminated and contain
+//! no interior NUL characters, so borrowed conversions are zero-cost and
+//! owned conversions reuse the existing buffer.
+
+use widestring::{U16CStr, U32CStr};
+
+use crate::wcstr::WCStr;
+
+macro_rules! impl_interop {
+    ($($ty:ty => $cstr:ident),* $(,)?) => {
+        $(
+            #[doc = concat!("Borrows a `WCStr<", stringify!($ty), ">` as a [`", stringify!($cstr), "`].")]
+            ///
+            /// This conversion is zero-cost.
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::wcstr;
+            #[doc = concat!("use widestring::", stringify!($cstr), ";")]
+            ///
+            #[doc = concat!("let s: &", stringify!($cstr), " = wcstr!(", stringify!($ty), ", \"hi\").into();")]
+            /// assert_eq!(s.to_string_lossy(), "hi");
+            /// ```
+            impl<'a> From<&'a WCStr<$ty>> for &'a $cstr {
+                #[inline]
+                fn from(s: &'a WCStr<$ty>) -> &'a $cstr {
+                    // SAFETY: A `WCStr` is NUL-terminated and has no interior
+                    //         NUL characters.
+                    unsafe { $cstr::from_slice_unchecked(s.to_slice_with_nul()) }
+                }
+            }
+
+            #[doc = concat!("Borrows a [`", stringify!($cstr), "`] as a `WCStr<", stringify!($ty), ">`.")]
+            ///
+            /// This conversion is zero-cost.
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::WCStr;
+            #[doc = concat!("use widestring::", stringify!($cstr), ";")]
+            ///
+            #[doc = concat!("let s = ", stringify!($cstr), "::from_slice_truncate(&[0x68, 0x69, 0x00]).unwrap();")]
+            #[doc = concat!("let w: &WCStr<", stringify!($ty), "> = s.into();")]
+            /// assert_eq!(w.to_slice(), &[0x68, 0x69]);
+            /// ```
+            impl<'a> From<&'a $cstr> for &'a WCStr<$ty> {
+                #[inline]
+                fn from(s: &'a $cstr) -> &'a WCStr<$ty> {
+                    // SAFETY: A C string from `widestring` is NUL-terminated
+                    //         and has no interior NUL characters.
+                    unsafe { WCStr::from_slice_with_nul_unchecked(s.as_slice_with_nul()) }
+                }
+            }
+        )*
+    };
+}
+
+impl_interop! {
+    u16 => U16CStr,
+    u32 => U32CStr,
+}
+
+#[cfg(feature = "alloc")]
+mod owned {
+    use widestring::{U16CString, U32CString};
+
+    use crate::wcstring::WCString;
+
+    macro_rules! impl_owned_interop {
+        ($($ty:ty => $cstring:ident),* $(,)?) => {
+            $(
+                #[doc = concat!("Converts a `WCString<", stringify!($ty), ">` into a [`", stringify!($cstring), "`].")]
+                ///
+                /// This conversion does not allocate.
+                ///
+                /// # Examples
+                ///
+                /// Basic usage:
+                ///
+                /// ```
+                /// use thicc::WCString;
+                #[doc = concat!("use widestring::", stringify!($cstring), ";")]
+                ///
+                #[doc = concat!("let mut s = WCString::<", stringify!($ty), ">::new();")]
+                /// s.push_str("hi");
+                ///
+                #[doc = concat!("let s: ", stringify!($cstring), " = s.into();")]
+                /// assert_eq!(s.to_string_lossy(), "hi");
+                /// ```
+                impl From<WCString<$ty>> for $cstring {
+                    #[inline]
+                    fn from(s: WCString<$ty>) -> $cstring {
+                        // SAFETY: The buffer of a `WCString` is NUL-terminated
+                        //         and has no interior NUL characters.
+                        unsafe { $cstring::from_vec_unchecked(s.into_vec_with_nul()) }
+                    }
+                }
+
+                #[doc = concat!("Converts a [`", stringify!($cstring), "`] into a `WCString<", stringify!($ty), ">`.")]
+                ///
+                /// This conversion does not allocate.
+                ///
+                /// # Examples
+                ///
+                /// Basic usage:
+                ///
+                /// ```
+                /// use thicc::WCString;
+                #[doc = concat!("use widestring::", stringify!($cstring), ";")]
+                ///
+                #[doc = concat!("let s = ", stringify!($cstring), "::from_str(\"hi\").unwrap();")]
+                #[doc = concat!("let s: WCString<", stringify!($ty), "> = s.into();")]
+                /// assert_eq!(s.as_wcstr().to_string_lossy(), "hi");
+                /// ```
+                impl From<$cstring> for WCString<$ty> {
+                    #[inline]
+                    fn from(s: $cstring) -> WCString<$ty> {
+                        // SAFETY: A C string from `widestring` is
+                        //         NUL-terminated and has no interior NUL
+                        //         characters.
+                        unsafe { WCString::from_vec_with_nul_unchecked(s.into_vec_with_nul()) }
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_owned_interop! {
+        u16 => U16CString,
+        u32 => U32CString,
+    }
+}
@@ -0,0 +1,196 @@
+use core::iter::FusedIterator;
+
+use crate::char::{Chars, DecodeWideError, SpecFind, Wide};
+use crate::wcstr::{FromSliceWithNulError, WCStr};
+
+/// An iterator over the positions of a wide character in a wide string.
+///
+/// This struct is created by the [`WCStr::matches`] and
+/// [`WCStr::find_all_nuls`] methods. See their documentation for more.
+///
+/// [`WCStr::matches`]: crate::WCStr::matches
+/// [`WCStr::find_all_nuls`]: crate::WCStr::find_all_nuls
+#[derive(Clone)]
+pub struct Matches<'a, T: Wide> {
+    haystack: &'a [T],
+    needle: T,
+    offset: usize,
+}
+
+impl<'a, T: Wide> Matches<'a, T> {
+    #[inline]
+    pub(crate) fn new(haystack: &'a [T], needle: T) -> Matches<'a, T> {
+        Matches {
+            haystack,
+            needle,
+            offset: 0,
+        }
+    }
+}
+
+impl<T: Wide> Iterator for Matches<'_, T> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        let pos = SpecFind::wmemchr(self.needle, &self.haystack[self.offset..])?;
+        let idx = self.offset + pos;
+        self.offset = idx + 1;
+        Some(idx)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.haystack.len() - self.offset))
+    }
+}
+
+impl<T: Wide> FusedIterator for Matches<'_, T> {}
+
+/// An iterator over the wide strings of a double NUL-terminated list.
+///
+/// This struct is created by the [`WCStr::split_list`] method.
+/// See its documentation for more.
+///
+/// [`WCStr::split_list`]: crate::WCStr::split_list
+#[derive(Clone)]
+pub struct WCStrList<'a, T: Wide> {
+    rest: &'a [T],
+    finished: bool,
+}
+
+impl<'a, T: Wide> WCStrList<'a, T> {
+    #[inline]
+    pub(crate) fn new(block: &'a [T]) -> WCStrList<'a, T> {
+        WCStrList {
+            rest: block,
+            finished: false,
+        }
+    }
+}
+
+impl<'a, T: Wide> Iterator for WCStrList<'a, T> {
+    type Item = Result<&'a WCStr<T>, FromSliceWithNulError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        match WCStr::from_slice_up_to_nul(self.rest) {
+            // The empty string terminates the list.
+            Ok((s, _)) if s.is_empty() => {
+                self.finished = true;
+                None
+            }
+            Ok((s, rest)) => {
+                self.rest = rest;
+                Some(Ok(s))
+            }
+            Err(e) => {
+                self.finished = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl<T: Wide> FusedIterator for WCStrList<'_, T> {}
+
+/// An iterator over the [`char`]s of a wide string and their positions,
+/// replacing invalid data with
+/// [the replacement character (`U+FFFD`)][U+FFFD].
+///
+/// This struct is created by the [`WCStr::char_indices_lossy`] method.
+/// See its documentation for more.
+///
+/// [`WCStr::char_indices_lossy`]: crate::WCStr::char_indices_lossy
+/// [U+FFFD]: char::REPLACEMENT_CHARACTER
+#[derive(Clone)]
+pub struct CharIndicesLossy<'a, T: Wide> {
+    chars: Chars<'a, T>,
+    offset: usize,
+}
+
+impl<'a, T: Wide> CharIndicesLossy<'a, T> {
+    #[inline]
+    pub(crate) fn new(chars: Chars<'a, T>) -> CharIndicesLossy<'a, T> {
+        CharIndicesLossy { chars, offset: 0 }
+    }
+}
+
+impl<T: Wide> Iterator for CharIndicesLossy<'_, T> {
+    type Item = (usize, char);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, char)> {
+        let idx = self.offset;
+        let c = match self.chars.next()? {
+            Ok(c) => {
+                self.offset += T::encode(c, &mut [T::NUL; 2]).len();
+                c
+            }
+            // Invalid data is always decoded one wide character at a time.
+            Err(_) => {
+                self.offset += 1;
+                char::REPLACEMENT_CHARACTER
+            }
+        };
+        Some((idx, c))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chars.size_hint()
+    }
+}
+
+impl<T: Wide> FusedIterator for CharIndicesLossy<'_, T> {}
+
+/// An iterator over the [`char`]s of a wide string, in reverse.
+///
+/// This struct is created by the [`WCStr::rchars`] method.
+/// See its documentation for more.
+///
+/// [`WCStr::rchars`]: crate::WCStr::rchars
+#[derive(Clone)]
+pub struct RChars<'a, T: Wide> {
+    slice: &'a [T],
+}
+
+impl<'a, T: Wide> RChars<'a, T> {
+    #[inline]
+    pub(crate) fn new(s: &'a WCStr<T>) -> RChars<'a, T> {
+        RChars {
+            slice: s.to_slice(),
+        }
+    }
+}
+
+impl<T: Wide> Iterator for RChars<'_, T> {
+    type Item = Result<char, DecodeWideError<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut start = self.slice.len().checked_sub(1)?;
+        if !T::is_char_boundary(self.slice, start) {
+            start -= 1;
+        }
+
+        // SAFETY: `start` is in bounds and the slice is always a prefix of a
+        //         wide string, so the tail starting at `start` is
+        //         NUL-terminated. The end of the slice is a `char` boundary, so
+        //         decoding the tail yields exactly the units from `start`.
+        let tail = unsafe { WCStr::from_ptr(self.slice.as_ptr().add(start)) };
+        let c = tail.chars().next();
+
+        self.slice = &self.slice[..start];
+        c
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        T::size_hint(self.slice.len())
+    }
+}
+
+impl<T: Wide> FusedIterator for RChars<'_, T> {}
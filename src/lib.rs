@@ -18,16 +18,30 @@ cfg_if::cfg_if! {
 mod internal_macros;
 
 mod char;
+mod display;
+#[cfg(feature = "widestring-interop")]
+mod interop;
+mod iter;
 // mod wstr;
 mod wcstr;
 
-pub use crate::char::{Chars, DecodeWideError, WChar, Wide};
-pub use crate::wcstr::{FromSliceWithNulError, WCStr};
+pub use crate::char::{
+    Chars, DecodeWideError, WCHAR_IS_UTF16, WCHAR_IS_UTF32, WCHAR_SIZE, WChar, Wide,
+};
+pub use crate::display::{Display, DisplayEscaped};
+pub use crate::iter::{CharIndicesLossy, Matches, RChars, WCStrList};
+pub use crate::wcstr::{
+    EncodeUtf8Error, FromSliceWithNulError, LenOverflowError, NoNulError, WCStr,
+};
 
 cfg_if::cfg_if! {
     if #[cfg(feature = "alloc")] {
         mod alloc;
         // mod wstring;
-        // mod wcstring;
+        mod wcstring;
+
+        pub use crate::wcstring::{
+            CharWidth, FromBytesError, FromVecWithNulError, InteriorNulError, WCString,
+        };
     }
 }
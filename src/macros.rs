@@ -35,18 +35,127 @@
 /// assert_eq!("Rust", RUST.to_string_lossy());
 /// assert_eq!("Rust", ALSO_RUST.to_string_lossy());
 /// ```
+///
+/// A string literal containing a NUL character fails to compile:
+///
+/// ```compile_fail
+/// use thicc::wcstr;
+///
+/// let s = wcstr!("a\0b");
+/// ```
 #[macro_export]
 macro_rules! wcstr {
-    ($ty:ident, $string:literal) => {
-        unsafe {
-            const STRING: &[$ty] = $crate::_wchar::wchz!($ty, $string);
-            $crate::WCStr::from_slice_with_nul_unchecked(STRING)
-        }
+    ($ty:ident, $string:literal) => {{
+        const STRING: &$crate::WCStr<$ty> =
+            $crate::WCStr::<$ty>::from_slice_with_nul_const($crate::_wchar::wchz!($ty, $string));
+        STRING
+    }};
+    ($string:literal) => {{
+        const STRING: &$crate::WCStr<$crate::WChar> =
+            $crate::WCStr::<$crate::WChar>::from_slice_with_nul_const($crate::_wchar::wchz!(
+                $string
+            ));
+        STRING
+    }};
+}
+
+/// Returns the length of a string literal as a wide string at compile time.
+///
+/// The result is the same as calling [`WCStr::len`] on the output of
+/// [`wcstr!`] with the same arguments, but is computed without scanning for
+/// the NUL-terminator at runtime.
+///
+/// [`WCStr::len`]: crate::WCStr::len
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use thicc::{wcstr, wcstr_len};
+///
+/// const LEN: usize = wcstr_len!("Rust");
+///
+/// assert_eq!(LEN, 4);
+/// assert_eq!(LEN, wcstr!("Rust").len());
+/// ```
+///
+/// UTF-16 usage:
+///
+/// ```
+/// use thicc::{wcstr, wcstr_len};
+///
+/// const LEN: usize = wcstr_len!(u16, "Rust🎉");
+///
+/// assert_eq!(LEN, 6);
+/// assert_eq!(LEN, wcstr!(u16, "Rust🎉").len());
+/// ```
+///
+/// UTF-32 usage:
+///
+/// ```
+/// use thicc::{wcstr, wcstr_len};
+///
+/// const LEN: usize = wcstr_len!(u32, "Rust🎉");
+///
+/// assert_eq!(LEN, 5);
+/// assert_eq!(LEN, wcstr!(u32, "Rust🎉").len());
+/// ```
+#[macro_export]
+macro_rules! wcstr_len {
+    ($ty:ident, $string:literal) => {{
+        const STRING: &[$ty] = $crate::_wchar::wch!($ty, $string);
+        STRING.len()
+    }};
+    ($string:literal) => {{
+        const STRING: &[$crate::WChar] = $crate::_wchar::wch!($string);
+        STRING.len()
+    }};
+}
+
+/// Creates a `WCString` using interpolation of runtime expressions.
+///
+/// The arguments are formatted exactly as with [`format!`] and the result is
+/// then encoded as a wide string, with an optional backing type as the first
+/// argument.
+///
+/// # Errors
+///
+/// Returns an [`InteriorNulError`] if the formatted string contains a NUL
+/// character.
+///
+/// [`InteriorNulError`]: crate::InteriorNulError
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use thicc::wcformat;
+///
+/// let s = wcformat!("{}/{}", "foo", 42).unwrap();
+///
+/// assert_eq!(s.as_wcstr().to_string_lossy(), "foo/42");
+/// ```
+///
+/// With a backing type:
+///
+/// ```
+/// use thicc::wcformat;
+///
+/// let s = wcformat!(u16, "{}🦀", 1).unwrap();
+/// assert_eq!(s.as_wcstr().to_slice(), &[0x0031, 0xD83E, 0xDD80]);
+///
+/// let e = wcformat!(u32, "a{}b", '\0').err().unwrap();
+/// assert_eq!(e.nul_position(), 1);
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! wcformat {
+    ($ty:ident, $($arg:tt)*) => {
+        $crate::WCString::<$ty>::__from_fmt(::core::format_args!($($arg)*))
     };
-    ($string:literal) => {
-        unsafe {
-            const STRING: &[$crate::WChar] = $crate::_wchar::wchz!($string);
-            $crate::WCStr::from_slice_with_nul_unchecked(STRING)
-        }
+    ($($arg:tt)*) => {
+        $crate::WCString::<$crate::WChar>::__from_fmt(::core::format_args!($($arg)*))
     };
 }
@@ -1,8 +1,14 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
 use core::mem;
+use core::ops::Range;
 use core::slice;
 
-use crate::char::{Chars, WChar, Wide};
+use crate::char::{Chars, DecodeWideError, WChar, Wide};
+use crate::display::{Display, DisplayEscaped};
+use crate::iter::{CharIndicesLossy, Matches, RChars, WCStrList};
 
 extern "C" {
     // HACK: Extern type to prevent `WCStr` from being sized.
@@ -13,7 +19,13 @@ extern "C" {
 #[repr(transparent)]
 pub struct WCStr<T: Wide = WChar>(PhantomData<T>, WCStrExtern);
 
+// SAFETY: A `WCStr` is an immutable sequence of plain integers, exactly like
+//         `[T]`. The extern type only opts out of the auto traits.
+unsafe impl<T: Wide> Send for WCStr<T> {}
+unsafe impl<T: Wide> Sync for WCStr<T> {}
+
 assert_impls!(WCStr: !Sized);
+assert_impls!(&WCStr: Send & Sync);
 static_assert!(mem::size_of::<&WCStr>() == mem::size_of::<*const WChar>());
 static_assert!(mem::size_of::<&WCStr>() == mem::size_of::<*mut WChar>());
 static_assert!(mem::align_of::<&WCStr>() == mem::align_of::<*const WChar>());
@@ -23,6 +35,7 @@ macro_rules! assert_repr {
     ($($ty:ident)*) => {
         $(
             assert_impls!(WCStr<$ty>: !Sized);
+            assert_impls!(&WCStr<$ty>: Send & Sync);
             static_assert!(mem::size_of::<&WCStr<$ty>>() == mem::size_of::<*const $ty>());
             static_assert!(mem::size_of::<&WCStr<$ty>>() == mem::size_of::<*mut $ty>());
             static_assert!(mem::align_of::<&WCStr<$ty>>() == mem::align_of::<*const $ty>());
@@ -78,6 +91,55 @@ impl<T: Wide> WCStr<T> {
         &*(ptr as *const WCStr<T>)
     }
 
+    /// Creates a `WCStr` from a raw pointer to a C-style wide string, reading
+    /// at most `max_units` wide characters in search of the NUL-terminator.
+    ///
+    /// Unlike [`WCStr::from_ptr`], a missing NUL-terminator is reported as an
+    /// error instead of reading indefinitely past the end of the string. This
+    /// makes it suitable for strings received from untrusted FFI boundaries.
+    ///
+    /// # Safety
+    ///
+    /// - `ptr` must be non-null.
+    ///
+    /// - The memory referenced by `ptr` must be valid for reads up to and
+    ///   including the NUL-terminator, or for `max_units` wide characters if
+    ///   there is no NUL-terminator within that bound.
+    ///
+    /// - The memory referenced by `ptr` must be valid for the returned
+    ///   lifetime.
+    ///
+    /// - The memory referenced by `ptr` must not be modified before the
+    ///   returned `WCStr` is dropped.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCStr;
+    ///
+    /// // Rust<nul>
+    /// let v: &[u16] = &[0x0052, 0x0075, 0x0073, 0x0074, 0x0000];
+    ///
+    /// unsafe {
+    ///     let s = WCStr::from_ptr_with_max(v.as_ptr(), v.len()).unwrap();
+    ///     assert_eq!(s.len(), 4);
+    ///
+    ///     // The NUL-terminator must be within the first `max_units` characters.
+    ///     assert!(WCStr::from_ptr_with_max(v.as_ptr(), 4).is_err());
+    /// }
+    /// ```
+    pub unsafe fn from_ptr_with_max<'a>(
+        ptr: *const T,
+        max_units: usize,
+    ) -> Result<&'a WCStr<T>, NoNulError> {
+        match find_nul(ptr, max_units) {
+            Some(_) => Ok(WCStr::from_ptr(ptr)),
+            None => Err(NoNulError::new(max_units)),
+        }
+    }
+
     /// Creates a `WCStr` from a byte slice.
     ///
     /// This function will cast the provided `slice` to a `CStr`
@@ -137,6 +199,136 @@ impl<T: Wide> WCStr<T> {
         }
     }
 
+    /// Creates a `WCStr` from the start of a slice of wide characters, up to
+    /// and including the first NUL-terminator.
+    ///
+    /// Returns the `WCStr` along with the remainder of the slice following the
+    /// NUL-terminator. This allows parsing packed sequences of strings, such as
+    /// the double NUL-terminated environment blocks used on Windows.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the slice does not contain a NUL character.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCStr;
+    /// use wchar::wch;
+    ///
+    /// // Each string is NUL-terminated, and the block ends with an empty string.
+    /// let block: &[u16] = wch!(u16, "A=1\0B=2\0\0");
+    ///
+    /// let mut vars = Vec::new();
+    /// let mut rest = block;
+    /// loop {
+    ///     let (s, tail) = WCStr::from_slice_up_to_nul(rest).unwrap();
+    ///     if s.is_empty() {
+    ///         break;
+    ///     }
+    ///     vars.push(s.to_string_lossy());
+    ///     rest = tail;
+    /// }
+    ///
+    /// assert_eq!(vars, ["A=1", "B=2"]);
+    /// ```
+    ///
+    /// A slice without a NUL character is an error:
+    ///
+    /// ```
+    /// use thicc::WCStr;
+    ///
+    /// let v: &[u32] = &[0x0061, 0x0062];
+    /// assert!(WCStr::from_slice_up_to_nul(v).is_err());
+    /// ```
+    pub fn from_slice_up_to_nul(slice: &[T]) -> Result<(&WCStr<T>, &[T]), FromSliceWithNulError> {
+        use crate::char::SpecFind;
+
+        match SpecFind::wmemchr(T::NUL, slice) {
+            Some(nul_pos) => {
+                let (s, rest) = slice.split_at(nul_pos + 1);
+                // SAFETY: `s` ends at the first NUL character of `slice`.
+                Ok((unsafe { WCStr::from_slice_with_nul_unchecked(s) }, rest))
+            }
+            None => Err(FromSliceWithNulError::not_nul_terminated()),
+        }
+    }
+
+    /// Returns an iterator over the wide strings of a double NUL-terminated
+    /// list.
+    ///
+    /// The list is a sequence of NUL-terminated wide strings, ending with an
+    /// empty string. This is the format of environment blocks and
+    /// multi-string registry values on Windows. Anything following the
+    /// terminating empty string is ignored.
+    ///
+    /// If the list is not terminated by an empty string, the iterator yields
+    /// an error after the last complete string.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCStr;
+    /// use wchar::wch;
+    ///
+    /// let block: &[u16] = wch!(u16, "A=1\0B=2\0\0");
+    /// let vars: Vec<String> = WCStr::split_list(block)
+    ///     .map(|s| s.unwrap().to_string_lossy())
+    ///     .collect();
+    ///
+    /// assert_eq!(vars, ["A=1", "B=2"]);
+    ///
+    /// let empty: &[u32] = &[0, 0];
+    /// assert_eq!(WCStr::split_list(empty).count(), 0);
+    /// ```
+    ///
+    /// A list that is not terminated by an empty string is an error:
+    ///
+    /// ```
+    /// use thicc::WCStr;
+    /// use wchar::wch;
+    ///
+    /// let block: &[u16] = wch!(u16, "A=1\0B=2");
+    /// let mut iter = WCStr::split_list(block);
+    ///
+    /// assert!(iter.next().unwrap().is_ok());
+    /// assert!(iter.next().unwrap().is_err());
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[inline]
+    pub fn split_list(block: &[T]) -> WCStrList<'_, T> {
+        WCStrList::new(block)
+    }
+
+    /// Returns an iterator over the positions of every NUL character in a
+    /// slice of wide characters.
+    ///
+    /// This is a debugging aid for finding out why
+    /// [`WCStr::from_slice_with_nul`] rejected a buffer, since its error only
+    /// reports the first interior NUL character.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCStr;
+    ///
+    /// // a<nul>b<nul><nul>
+    /// let v: &[u16] = &[0x0061, 0x0000, 0x0062, 0x0000, 0x0000];
+    ///
+    /// assert!(WCStr::from_slice_with_nul(v).is_err());
+    /// assert_eq!(WCStr::find_all_nuls(v).collect::<Vec<_>>(), [1, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn find_all_nuls(slice: &[T]) -> Matches<'_, T> {
+        Matches::new(slice, T::NUL)
+    }
+
     /// Creates a `WCStr` from a slice of wide characters with a NUL-terminator.
     ///
     /// No checks are performed that `slice` is a valid `WCStr`.
@@ -210,6 +402,44 @@ impl<T: Wide> WCStr<T> {
         self as *const WCStr<T> as *const T
     }
 
+    /// Returns the two raw pointers spanning the wide string.
+    ///
+    /// The returned range is half-open, so the end pointer points to the
+    /// NUL-terminator, one past the last non-NUL wide character. This is the
+    /// same range as calling [`slice::as_ptr_range`] on [`WCStr::to_slice`].
+    ///
+    /// See [`WCStr::as_ptr`] for warnings on using these pointers.
+    ///
+    /// > **Note**: This operation is not zero-cost, requiring iteration through
+    /// > all bytes of the string to calculate the length.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::slice;
+    /// use thicc::wcstr;
+    ///
+    /// let s = wcstr!(u16, "hello");
+    /// let range = s.as_ptr_range();
+    ///
+    /// unsafe {
+    ///     let len = range.end.offset_from(range.start) as usize;
+    ///     let v = slice::from_raw_parts(range.start, len);
+    ///
+    ///     assert_eq!(v, s.to_slice());
+    ///     assert_eq!(*range.end, 0);
+    /// }
+    /// ```
+    #[inline]
+    pub fn as_ptr_range(&self) -> Range<*const T> {
+        let start = self.as_ptr();
+        // SAFETY: The NUL-terminator is within the same allocation.
+        let end = unsafe { start.add(self.len()) };
+        start..end
+    }
+
     /// Converts a `WCStr` into a slice of wide characters.
     ///
     /// The returned slice will **not** contain the trailing NUL-terminator.
@@ -280,10 +510,79 @@ impl<T: Wide> WCStr<T> {
         }
     }
 
+    /// Converts a `WCStr` into a slice of bytes.
+    ///
+    /// The bytes are the in-memory representation of the wide characters,
+    /// and so are in the native endianness of the target platform. The
+    /// returned slice will **not** contain the trailing NUL-terminator, and
+    /// has a length of `len() * size_of::<T>()`.
+    ///
+    /// > **Note**: This operation is not zero-cost, requiring iteration through
+    /// > all bytes of the string to calculate the length.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCStr;
+    ///
+    /// // Hi<nul>
+    /// let v: &[u16] = &[0x0048, 0x0069, 0x0000];
+    /// let s = WCStr::from_slice_with_nul(v).unwrap();
+    ///
+    /// let expected: Vec<u8> = s.to_slice().iter().flat_map(|u| u.to_ne_bytes()).collect();
+    /// assert_eq!(s.as_bytes(), &expected[..]);
+    /// assert_eq!(s.as_bytes().len(), 4);
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        let units = self.to_slice();
+        // SAFETY: Wide characters are plain integers with no padding, and the
+        //         memory is valid for reads for the lifetime of `self`.
+        unsafe { slice::from_raw_parts(units.as_ptr() as *const u8, mem::size_of_val(units)) }
+    }
+
+    /// Converts a `WCStr` into a slice of bytes containing the trailing
+    /// NUL-terminator.
+    ///
+    /// This function is the equivalent of [`WCStr::as_bytes`] except that it
+    /// will retain the bytes of the trailing NUL-terminator, giving a length
+    /// of `(len() + 1) * size_of::<T>()`.
+    ///
+    /// > **Note**: This operation is not zero-cost, requiring iteration through
+    /// > all bytes of the string to calculate the length.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCStr;
+    ///
+    /// // 🦀<nul>
+    /// let v: &[u32] = &[0x0001_F980, 0x0000_0000];
+    /// let s = WCStr::from_slice_with_nul(v).unwrap();
+    ///
+    /// let bytes = s.as_bytes_with_nul();
+    ///
+    /// assert_eq!(bytes.len(), 8);
+    /// assert_eq!(&bytes[..4], &0x0001_F980u32.to_ne_bytes());
+    /// assert_eq!(&bytes[4..], &[0, 0, 0, 0]);
+    /// ```
+    #[inline]
+    pub fn as_bytes_with_nul(&self) -> &[u8] {
+        let units = self.to_slice_with_nul();
+        // SAFETY: Wide characters are plain integers with no padding, and the
+        //         memory is valid for reads for the lifetime of `self`.
+        unsafe { slice::from_raw_parts(units.as_ptr() as *const u8, mem::size_of_val(units)) }
+    }
+
     /// Returns the length of a wide string.
     ///
     /// The length is the number of non-NUL wide characters that precede the
-    /// NUL-terminator.
+    /// NUL-terminator. For UTF-16 backings this is not necessarily the number
+    /// of [`char`]s in the string, see [`WCStr::count_chars`].
     ///
     /// > **Note**: This operation is not zero-cost, requiring iteration through
     /// > all bytes of the string to calculate the length.
@@ -312,81 +611,2420 @@ impl<T: Wide> WCStr<T> {
         unsafe { SpecLen::wcslen(self.as_ptr()) }
     }
 
-    /// Returns an iterator over the [`char`]s of a wide string.
+    /// Returns the length of a wide string, returning an error rather than
+    /// overflowing if the string is too long to be represented as a slice.
+    ///
+    /// A slice of `T` can hold at most `isize::MAX / size_of::<T>()` elements.
+    /// [`WCStr::len`] assumes that no string is longer than that, which is
+    /// not guaranteed for a wide string received from untrusted code. This
+    /// method counts the wide characters one at a time and stops at the
+    /// limit, without relying on pointer offsets.
+    ///
+    /// > **Note**: This operation is not zero-cost, requiring iteration through
+    /// > all bytes of the string to calculate the length.
     ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```
-    /// use thicc::WCStr;
+    /// use thicc::wcstr;
     ///
-    /// // 𝄞mus<invalid>ic<invalid><nul>
-    /// let v: &[u16] = &[
-    ///     0xD834, 0xDD1E, 0x006D, 0x0075, 0x0073, 0xDD1E, 0x0069, 0x0063,
-    ///     0xD834, 0x0000,
-    /// ];
-    /// let s = WCStr::from_slice_with_nul(v).unwrap();
+    /// assert_eq!(wcstr!(u16, "Rust").try_len(), Ok(4));
+    /// assert_eq!(wcstr!(u32, "").try_len(), Ok(0));
+    /// ```
+    pub fn try_len(&self) -> Result<usize, LenOverflowError> {
+        let max = isize::MAX as usize / mem::size_of::<T>();
+
+        // SAFETY: Safe references to `WCStr<T>` can only exist if they point to
+        //         memory that has a NUL-terminator, and the scan stops at the
+        //         first one found.
+        unsafe { find_nul(self.as_ptr(), max + 1) }.ok_or_else(|| LenOverflowError::new(max))
+    }
+
+    /// Returns the length of a wide string if it is at most `max`, without
+    /// reading further into a longer string.
     ///
-    /// let mut iter = s.chars().map(|r| r.map_err(|e| e.code()));
+    /// Returns `None` if the length is greater than `max`. At most `max + 1`
+    /// wide characters are read, including the NUL-terminator. This bounds
+    /// the cost of checking the length of a string that may be very long.
+    ///
+    /// A `WCStr` is always NUL-terminated, so this cannot detect a missing
+    /// NUL-terminator. To guard against one in a string received over FFI,
+    /// use [`WCStr::from_ptr_with_max`] when creating the `WCStr` instead.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
     ///
-    /// assert_eq!(iter.next(), Some(Ok('𝄞')));
-    /// assert_eq!(iter.next(), Some(Ok('m')));
-    /// assert_eq!(iter.next(), Some(Ok('u')));
-    /// assert_eq!(iter.next(), Some(Ok('s')));
-    /// assert_eq!(iter.next(), Some(Err(0xDD1E)));
-    /// assert_eq!(iter.next(), Some(Ok('i')));
-    /// assert_eq!(iter.next(), Some(Ok('c')));
-    /// assert_eq!(iter.next(), Some(Err(0xD834)));
-    /// assert_eq!(iter.next(), None);
     /// ```
-    pub fn chars(&self) -> Chars<'_, T> {
+    /// use thicc::wcstr;
+    ///
+    /// let s = wcstr!(u16, "Rust");
+    ///
+    /// assert_eq!(s.checked_len(16), Some(4));
+    /// assert_eq!(s.checked_len(4), Some(4));
+    /// assert_eq!(s.checked_len(3), None);
+    /// ```
+    pub fn checked_len(&self, max: usize) -> Option<usize> {
         // SAFETY: Safe references to `WCStr<T>` can only exist if they point to
-        //         memory that has a NUL-terminator.
-        Chars::new(self.as_ptr())
+        //         memory that has a NUL-terminator, and the scan stops at the
+        //         first one found.
+        unsafe { find_nul(self.as_ptr(), max.saturating_add(1)) }
     }
-}
 
-cfg_if::cfg_if! {
-    if #[cfg(feature = "alloc")] {
-        use crate::alloc::String;
+    /// Returns the length of a wide string in bytes, without the
+    /// NUL-terminator.
+    ///
+    /// This is the same as `self.len() * mem::size_of::<T>()`, and is the
+    /// length of the slice returned by [`WCStr::as_bytes`].
+    ///
+    /// > **Note**: This operation is not zero-cost, requiring iteration through
+    /// > all bytes of the string to calculate the length.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// assert_eq!(wcstr!(u16, "Rust").byte_len(), 8);
+    /// assert_eq!(wcstr!(u32, "Rust").byte_len(), 16);
+    /// ```
+    #[inline]
+    pub fn byte_len(&self) -> usize {
+        self.len() * mem::size_of::<T>()
+    }
 
-        impl<T: Wide> WCStr<T> {
-            /// Decodes a wide character string into a [`String`], replacing
-            /// invalid data with [the replacement character (`U+FFFD`)][U+FFFD].
-            ///
-            /// [U+FFFD]: char::REPLACEMENT_CHARACTER
-            ///
-            /// # Examples
-            ///
-            /// Basic usage:
-            ///
-            /// ```
-            /// use thicc::WCStr;
-            ///
-            /// // 𝄞mus<invalid>ic<invalid>
-            /// let v: &[u16] = &[
-            ///     0xD834, 0xDD1E, 0x006d, 0x0075, 0x0073, 0xDD1E, 0x0069, 0x0063, 0xD834, 0x0000,
-            /// ];
-            /// let s = WCStr::from_slice_with_nul(v).unwrap();
-            ///
-            /// assert_eq!("𝄞mus\u{FFFD}ic\u{FFFD}", s.to_string_lossy());
-            /// ```
-            #[inline]
-            pub fn to_string_lossy(&self) -> String {
-                self.chars().map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER)).collect()
-            }
-        }
+    /// Returns the length of a wide string in bytes, including the
+    /// NUL-terminator.
+    ///
+    /// This is the length of the slice returned by
+    /// [`WCStr::as_bytes_with_nul`].
+    ///
+    /// > **Note**: This operation is not zero-cost, requiring iteration through
+    /// > all bytes of the string to calculate the length.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// assert_eq!(wcstr!(u16, "Rust").byte_len_with_nul(), 10);
+    /// assert_eq!(wcstr!(u32, "Rust").byte_len_with_nul(), 20);
+    /// ```
+    #[inline]
+    pub fn byte_len_with_nul(&self) -> usize {
+        (self.len() + 1) * mem::size_of::<T>()
     }
-}
 
-/// An error indicating that a NUL byte was not in the expected position.
-///
-/// The slice used to create a [`WCStr`] must have one and only one NUL
-/// character, positioned at the end.
-///
-/// This error is created by the [`WCStr::from_slice_with_nul`] method.
-/// See its documentation for more.
+    /// Returns `true` if the wide string has a length of zero.
+    ///
+    /// Unlike [`WCStr::len`], this only needs to check the first wide
+    /// character.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// assert!(wcstr!("").is_empty());
+    /// assert!(!wcstr!("a").is_empty());
+    /// ```
+    ///
+    /// The default `&WCStr` is empty:
+    ///
+    /// ```
+    /// use thicc::WCStr;
+    ///
+    /// assert!(<&WCStr>::default().is_empty());
+    /// assert_eq!(<&WCStr>::default().len(), 0);
+    /// assert_eq!(<&WCStr<u16>>::default().to_slice_with_nul(), &[0]);
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        // SAFETY: Safe references to `WCStr<T>` can only exist if they point to
+        //         memory that has a NUL-terminator.
+        unsafe { *self.as_ptr() == T::NUL }
+    }
+
+    /// Returns the number of [`char`]s that can be decoded from a wide string.
+    ///
+    /// Unlike [`WCStr::len`], which counts wide characters, this counts
+    /// Unicode scalar values. A surrogate pair in a UTF-16 backing counts as a
+    /// single [`char`], and invalid data is not counted at all. To count
+    /// invalid data as well, use [`WCStr::char_count`].
+    ///
+    /// > **Note**: This operation is not zero-cost, requiring the whole string
+    /// > to be decoded.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::{wcstr, WCStr};
+    ///
+    /// let s = wcstr!(u16, "Rust🎉");
+    ///
+    /// assert_eq!(s.len(), 6);
+    /// assert_eq!(s.count_chars(), 5);
+    ///
+    /// // ab<invalid><nul>
+    /// let v: &[u16] = &[0x0061, 0x0062, 0xD834, 0x0000];
+    /// let s = WCStr::from_slice_with_nul(v).unwrap();
+    ///
+    /// assert_eq!(s.count_chars(), 2);
+    /// ```
+    #[inline]
+    pub fn count_chars(&self) -> usize {
+        self.chars().filter(Result::is_ok).count()
+    }
+
+    /// Returns the number of items that [`WCStr::chars`] would yield.
+    ///
+    /// For UTF-32 backings every wide character decodes to exactly one item,
+    /// so this is the same as [`WCStr::len`] and no decoding takes place. For
+    /// UTF-16 backings the string is decoded to account for surrogate pairs.
+    ///
+    /// Invalid data is counted, so this is also the number of [`char`]s in the
+    /// output of [`WCStr::to_string_lossy`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::{wcstr, WCStr};
+    ///
+    /// assert_eq!(wcstr!(u16, "Rust🎉").char_count(), 5);
+    /// assert_eq!(wcstr!(u32, "Rust🎉").char_count(), 5);
+    ///
+    /// // ab<invalid><nul>
+    /// let v: &[u16] = &[0x0061, 0x0062, 0xD834, 0x0000];
+    /// let s = WCStr::from_slice_with_nul(v).unwrap();
+    ///
+    /// assert_eq!(s.char_count(), 3);
+    /// ```
+    #[inline]
+    pub fn char_count(&self) -> usize {
+        self.chars().count()
+    }
+
+    /// Returns `true` if the wide string contains `needle` as a contiguous
+    /// sequence of wide characters.
+    ///
+    /// An empty `needle` is always contained.
+    ///
+    /// > **Note**: This operation is not zero-cost, requiring iteration through
+    /// > all bytes of the string to calculate the length.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    /// use wchar::wch;
+    ///
+    /// let s = wcstr!(u16, "bananas");
+    ///
+    /// assert!(s.contains(wch!(u16, "nan")));
+    /// assert!(s.contains(wch!(u16, "s")));
+    /// assert!(s.contains(&[]));
+    /// assert!(!s.contains(wch!(u16, "nab")));
+    /// assert!(!s.contains(wch!(u16, "bananas!")));
+    /// ```
+    pub fn contains(&self, needle: &[T]) -> bool {
+        use crate::char::SpecFind;
+
+        let (&first, rest) = match needle.split_first() {
+            Some(split) => split,
+            None => return true,
+        };
+
+        let haystack = self.to_slice();
+        let mut offset = 0;
+        while let Some(pos) = SpecFind::wmemchr(first, &haystack[offset..]) {
+            let start = offset + pos + 1;
+            if haystack[start..].starts_with(rest) {
+                return true;
+            }
+            offset = start;
+        }
+        false
+    }
+
+    /// Returns an iterator over the positions of every occurrence of `needle`
+    /// in the wide string.
+    ///
+    /// The positions are indexes of wide characters, as in
+    /// [`WCStr::to_slice`].
+    ///
+    /// > **Note**: This operation is not zero-cost, requiring iteration through
+    /// > all bytes of the string to calculate the length.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::{wcstr, WChar};
+    ///
+    /// let s = wcstr!("a.b.c.d");
+    /// let dots: Vec<usize> = s.matches('.' as WChar).collect();
+    ///
+    /// assert_eq!(dots, [1, 3, 5]);
+    /// assert_eq!(s.matches('!' as WChar).next(), None);
+    /// ```
+    #[inline]
+    pub fn matches(&self, needle: T) -> Matches<'_, T> {
+        Matches::new(self.to_slice(), needle)
+    }
+
+    /// Returns an iterator over the [`char`]s of a wide string.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCStr;
+    ///
+    /// // 𝄞mus<invalid>ic<invalid><nul>
+    /// let v: &[u16] = &[
+    ///     0xD834, 0xDD1E, 0x006D, 0x0075, 0x0073, 0xDD1E, 0x0069, 0x0063,
+    ///     0xD834, 0x0000,
+    /// ];
+    /// let s = WCStr::from_slice_with_nul(v).unwrap();
+    ///
+    /// let mut iter = s.chars().map(|r| r.map_err(|e| e.code()));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok('𝄞')));
+    /// assert_eq!(iter.next(), Some(Ok('m')));
+    /// assert_eq!(iter.next(), Some(Ok('u')));
+    /// assert_eq!(iter.next(), Some(Ok('s')));
+    /// assert_eq!(iter.next(), Some(Err(0xDD1E)));
+    /// assert_eq!(iter.next(), Some(Ok('i')));
+    /// assert_eq!(iter.next(), Some(Ok('c')));
+    /// assert_eq!(iter.next(), Some(Err(0xD834)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// Signed backings, such as [`WChar`] on some platforms, are decoded by
+    /// their bit pattern, so wide characters above `0x7FFF` or `0x7FFF_FFFF`
+    /// behave the same as their unsigned counterparts:
+    ///
+    /// ```
+    /// use thicc::WCStr;
+    ///
+    /// // 🦀<U+FFFF><invalid><nul>
+    /// let v: &[i16] = &[0xD83Eu16 as i16, 0xDD80u16 as i16, -1, 0xDC00u16 as i16, 0x0000];
+    /// let s = WCStr::from_slice_with_nul(v).unwrap();
+    ///
+    /// let mut iter = s.chars().map(|r| r.map_err(|e| e.code() as u16));
+    ///
+    /// assert_eq!(s.len(), 4);
+    /// assert_eq!(iter.next(), Some(Ok('🦀')));
+    /// assert_eq!(iter.next(), Some(Ok('\u{FFFF}')));
+    /// assert_eq!(iter.next(), Some(Err(0xDC00)));
+    /// assert_eq!(iter.next(), None);
+    ///
+    /// // 🦀<invalid><U+FFFF><nul>
+    /// let v: &[i32] = &[0x0001_F980, -1, 0x0000_FFFF, 0x0000_0000];
+    /// let s = WCStr::from_slice_with_nul(v).unwrap();
+    ///
+    /// let mut iter = s.chars().map(|r| r.map_err(|e| e.code() as u32));
+    ///
+    /// assert_eq!(s.len(), 3);
+    /// assert_eq!(iter.next(), Some(Ok('🦀')));
+    /// assert_eq!(iter.next(), Some(Err(0xFFFF_FFFF)));
+    /// assert_eq!(iter.next(), Some(Ok('\u{FFFF}')));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn chars(&self) -> Chars<'_, T> {
+        // SAFETY: Safe references to `WCStr<T>` can only exist if they point to
+        //         memory that has a NUL-terminator.
+        Chars::new(self.as_ptr())
+    }
+
+    /// Returns an iterator over the [`char`]s of a wide string and their
+    /// positions, replacing invalid data with
+    /// [the replacement character (`U+FFFD`)][U+FFFD].
+    ///
+    /// The positions are indexes of wide characters, as in
+    /// [`WCStr::to_slice`]. Each invalid wide character is replaced
+    /// separately.
+    ///
+    /// [U+FFFD]: core::char::REPLACEMENT_CHARACTER
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCStr;
+    ///
+    /// // a🦀<invalid>b<nul>
+    /// let v: &[u16] = &[0x0061, 0xD83E, 0xDD80, 0xDC00, 0x0062, 0x0000];
+    /// let s = WCStr::from_slice_with_nul(v).unwrap();
+    ///
+    /// let indices: Vec<(usize, char)> = s.char_indices_lossy().collect();
+    ///
+    /// assert_eq!(indices, [(0, 'a'), (1, '🦀'), (3, '\u{FFFD}'), (4, 'b')]);
+    /// ```
+    #[inline]
+    pub fn char_indices_lossy(&self) -> CharIndicesLossy<'_, T> {
+        CharIndicesLossy::new(self.chars())
+    }
+
+    /// Checks that a wide string is entirely valid Unicode.
+    ///
+    /// Returns the position and error of the first wide character that cannot
+    /// be decoded, stopping as soon as it is found. The position is an index
+    /// of wide characters, as in [`WCStr::to_slice`]. No allocation takes
+    /// place.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::{wcstr, WCStr};
+    ///
+    /// assert!(wcstr!(u16, "🦀rust").validate().is_ok());
+    ///
+    /// // 🦀<invalid>a<invalid><nul>
+    /// let v: &[u16] = &[0xD83E, 0xDD80, 0xDC00, 0x0061, 0xD800, 0x0000];
+    /// let s = WCStr::from_slice_with_nul(v).unwrap();
+    ///
+    /// let (pos, err) = s.validate().unwrap_err();
+    /// assert_eq!(pos, 2);
+    /// assert_eq!(err.code(), 0xDC00);
+    /// ```
+    pub fn validate(&self) -> Result<(), (usize, DecodeWideError<T>)> {
+        let mut pos = 0;
+        for c in self.chars() {
+            match c {
+                Ok(c) => pos += T::encode(c, &mut [T::NUL; 2]).len(),
+                Err(e) => return Err((pos, e)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Decodes the first [`char`] of a wide string, without decoding the rest
+    /// of the string.
+    ///
+    /// Returns `None` if the string is empty.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::{wcstr, WCStr};
+    ///
+    /// assert_eq!(wcstr!(u16, "🦀rust").first_char(), Some(Ok('🦀')));
+    /// assert_eq!(wcstr!(u32, "").first_char(), None);
+    ///
+    /// // <invalid>a<nul>
+    /// let s = WCStr::<u16>::from_slice_with_nul(&[0xDC00, 0x0061, 0x0000]).unwrap();
+    /// assert_eq!(s.first_char().unwrap().unwrap_err().code(), 0xDC00);
+    /// ```
+    #[inline]
+    pub fn first_char(&self) -> Option<Result<char, DecodeWideError<T>>> {
+        self.chars().next()
+    }
+
+    /// Decodes the [`char`] at index `n` of a wide string, without decoding
+    /// the rest of the string.
+    ///
+    /// Returns `None` if the string has no more than `n` [`char`]s. Invalid
+    /// data counts as a single [`char`], as in [`WCStr::chars`].
+    ///
+    /// For UTF-32 backings only the `n`th wide character is decoded, after
+    /// checking that the string is long enough. For UTF-16 backings the first
+    /// `n` [`char`]s must be decoded, since surrogate pairs make the position
+    /// of each [`char`] unknown in advance.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// assert_eq!(wcstr!(u16, "🦀rust").nth_char(1), Some(Ok('r')));
+    /// assert_eq!(wcstr!(u32, "🦀rust").nth_char(4), Some(Ok('t')));
+    /// assert_eq!(wcstr!(u32, "🦀rust").nth_char(5), None);
+    /// ```
+    #[inline]
+    pub fn nth_char(&self, n: usize) -> Option<Result<char, DecodeWideError<T>>> {
+        use crate::char::SpecNth;
+
+        SpecNth::nth_char(self, n)
+    }
+
+    /// Decodes the [`char`] containing the wide character at position
+    /// `unit_idx` of a wide string.
+    ///
+    /// For UTF-16 backings, if `unit_idx` is the trailing half of a surrogate
+    /// pair the whole pair is decoded. Returns `None` if `unit_idx` is not
+    /// less than the length of the string. Only the string up to `unit_idx`
+    /// is read, so the full length is never calculated.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// // a, then the surrogate pair for 🦀.
+    /// let s = wcstr!(u16, "a🦀");
+    ///
+    /// assert_eq!(s.char_at_unit(0), Some(Ok('a')));
+    /// assert_eq!(s.char_at_unit(1), Some(Ok('🦀')));
+    /// assert_eq!(s.char_at_unit(2), Some(Ok('🦀')));
+    /// assert_eq!(s.char_at_unit(3), None);
+    /// ```
+    pub fn char_at_unit(&self, unit_idx: usize) -> Option<Result<char, DecodeWideError<T>>> {
+        if self.checked_len(unit_idx).is_some() {
+            return None;
+        }
+
+        // SAFETY: There are more than `unit_idx` wide characters before the
+        //         NUL-terminator.
+        let units = unsafe { slice::from_raw_parts(self.as_ptr(), unit_idx + 1) };
+        let start = if T::is_char_boundary(units, unit_idx) {
+            unit_idx
+        } else {
+            unit_idx - 1
+        };
+
+        // SAFETY: The suffix starting at `start` is a valid `WCStr`.
+        unsafe { WCStr::from_ptr(self.as_ptr().add(start)) }.first_char()
+    }
+
+    /// Returns the wide string without a leading byte order mark (`U+FEFF`),
+    /// if there is one.
+    ///
+    /// The byte order mark is a single wide character for both UTF-16 and
+    /// UTF-32 backings, and the rest of the string is still NUL-terminated,
+    /// so this borrows from `self` without allocating. A leading `U+FFFE`,
+    /// which indicates that the string has the wrong byte order, is not
+    /// stripped.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// assert!(wcstr!(u16, "\u{FEFF}hi").strip_bom() == wcstr!(u16, "hi"));
+    /// assert!(wcstr!(u32, "\u{FEFF}hi").strip_bom() == wcstr!(u32, "hi"));
+    /// assert!(wcstr!(u16, "hi").strip_bom() == wcstr!(u16, "hi"));
+    /// assert!(wcstr!(u16, "\u{FFFE}hi").strip_bom() == wcstr!(u16, "\u{FFFE}hi"));
+    /// ```
+    pub fn strip_bom(&self) -> &WCStr<T> {
+        match self.first_char() {
+            // SAFETY: The byte order mark is a single wide character before the
+            //         NUL-terminator, so the suffix after it is a valid `WCStr`.
+            Some(Ok('\u{FEFF}')) => unsafe { WCStr::from_ptr(self.as_ptr().add(1)) },
+            _ => self,
+        }
+    }
+
+    /// Decodes the last [`char`] of a wide string, without decoding the rest
+    /// of the string.
+    ///
+    /// Returns `None` if the string is empty. For UTF-16 backings a trailing
+    /// surrogate pair is decoded as a single [`char`].
+    ///
+    /// > **Note**: This operation is not zero-cost, requiring iteration through
+    /// > all bytes of the string to calculate the length.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::{wcstr, WCStr};
+    ///
+    /// assert_eq!(wcstr!(u16, "rust🦀").last_char(), Some(Ok('🦀')));
+    /// assert_eq!(wcstr!(u32, "rust🦀").last_char(), Some(Ok('🦀')));
+    /// assert_eq!(wcstr!(u16, "").last_char(), None);
+    ///
+    /// // a<invalid><nul>
+    /// let s = WCStr::<u16>::from_slice_with_nul(&[0x0061, 0xD800, 0x0000]).unwrap();
+    /// assert_eq!(s.last_char().unwrap().unwrap_err().code(), 0xD800);
+    /// ```
+    #[inline]
+    pub fn last_char(&self) -> Option<Result<char, DecodeWideError<T>>> {
+        self.rchars().next()
+    }
+
+    /// Returns an iterator over the [`char`]s of a wide string, in reverse.
+    ///
+    /// The [`char`]s are the same as those of [`WCStr::chars`], including any
+    /// errors, but yielded from the end of the string. For UTF-16 backings a
+    /// surrogate pair is decoded as a single [`char`], as when decoding
+    /// forwards.
+    ///
+    /// > **Note**: This operation is not zero-cost, requiring iteration through
+    /// > all bytes of the string to calculate the length before the first
+    /// > [`char`] is decoded.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::{wcstr, WCStr};
+    ///
+    /// let s = wcstr!(u16, "a🦀b");
+    /// let rev: Vec<char> = s.rchars().map(Result::unwrap).collect();
+    ///
+    /// assert_eq!(rev, ['b', '🦀', 'a']);
+    ///
+    /// // <invalid>🦀<invalid><nul>
+    /// let v: &[u16] = &[0xD800, 0xD83E, 0xDD80, 0xDC00, 0x0000];
+    /// let s = WCStr::from_slice_with_nul(v).unwrap();
+    ///
+    /// let mut fwd: Vec<_> = s.chars().collect();
+    /// fwd.reverse();
+    ///
+    /// assert_eq!(s.rchars().collect::<Vec<_>>(), fwd);
+    /// ```
+    ///
+    /// Finding a file extension:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// let s = wcstr!(u16, "archive.tar.gz");
+    /// let ext: String = s.rchars().map(Result::unwrap).take_while(|&c| c != '.').collect();
+    ///
+    /// assert_eq!(ext, "zg");
+    /// ```
+    #[inline]
+    pub fn rchars(&self) -> RChars<'_, T> {
+        RChars::new(self)
+    }
+
+    /// Finds the closest wide character position not exceeding `index` that
+    /// does not split an encoded [`char`].
+    ///
+    /// For UTF-16 backings this moves `index` back by one if it lies between
+    /// the two halves of a surrogate pair. For UTF-32 backings every position
+    /// is a boundary. If `index` is greater than the length of the string,
+    /// the length is returned.
+    ///
+    /// > **Note**: This operation is not zero-cost, requiring iteration through
+    /// > all bytes of the string to calculate the length.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// // a, then the surrogate pair for 🦀.
+    /// let s = wcstr!(u16, "a🦀");
+    ///
+    /// assert_eq!(s.floor_char_boundary(1), 1);
+    /// assert_eq!(s.floor_char_boundary(2), 1);
+    /// assert_eq!(s.floor_char_boundary(3), 3);
+    /// assert_eq!(s.floor_char_boundary(8), 3);
+    ///
+    /// assert_eq!(wcstr!(u32, "a🦀").floor_char_boundary(1), 1);
+    /// ```
+    pub fn floor_char_boundary(&self, index: usize) -> usize {
+        let units = self.to_slice();
+        if index >= units.len() {
+            units.len()
+        } else if T::is_char_boundary(units, index) {
+            index
+        } else {
+            index - 1
+        }
+    }
+
+    /// Finds the closest wide character position not below `index` that does
+    /// not split an encoded [`char`].
+    ///
+    /// For UTF-16 backings this moves `index` forward by one if it lies
+    /// between the two halves of a surrogate pair. For UTF-32 backings every
+    /// position is a boundary. If `index` is greater than the length of the
+    /// string, the length is returned.
+    ///
+    /// > **Note**: This operation is not zero-cost, requiring iteration through
+    /// > all bytes of the string to calculate the length.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// // a, then the surrogate pair for 🦀.
+    /// let s = wcstr!(u16, "a🦀");
+    ///
+    /// assert_eq!(s.ceil_char_boundary(1), 1);
+    /// assert_eq!(s.ceil_char_boundary(2), 3);
+    /// assert_eq!(s.ceil_char_boundary(8), 3);
+    /// ```
+    pub fn ceil_char_boundary(&self, index: usize) -> usize {
+        let units = self.to_slice();
+        if index >= units.len() {
+            units.len()
+        } else if T::is_char_boundary(units, index) {
+            index
+        } else {
+            index + 1
+        }
+    }
+
+    /// Compares at most the first `n` wide characters of two wide strings.
+    ///
+    /// Like C's `wcsncmp`, comparison stops early at the end of either string,
+    /// so neither length needs to be calculated up front. Wide characters are
+    /// compared as values of `T`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use thicc::wcstr;
+    ///
+    /// let a = wcstr!(u16, "HDR:alpha");
+    /// let b = wcstr!(u16, "HDR:beta");
+    ///
+    /// assert_eq!(a.cmp_up_to(b, 4), Ordering::Equal);
+    /// assert_eq!(a.cmp_up_to(b, 5), Ordering::Less);
+    /// assert_eq!(b.cmp_up_to(a, 100), Ordering::Greater);
+    ///
+    /// // A shorter string is less than a longer string with the same prefix.
+    /// assert_eq!(wcstr!(u16, "HDR").cmp_up_to(a, 100), Ordering::Less);
+    /// assert_eq!(a.cmp_up_to(a, usize::MAX), Ordering::Equal);
+    /// ```
+    pub fn cmp_up_to(&self, other: &WCStr<T>, n: usize) -> Ordering {
+        let mut a = self.as_ptr();
+        let mut b = other.as_ptr();
+
+        // SAFETY: Safe references to `WCStr<T>` can only exist if they point to
+        //         memory that has a NUL-terminator, and neither pointer is
+        //         advanced past the first NUL-terminator found.
+        unsafe {
+            for _ in 0..n {
+                let (u, v) = (*a, *b);
+                match u.cmp(&v) {
+                    // The units are equal, so both strings end here.
+                    Ordering::Equal if u == T::NUL => break,
+                    Ordering::Equal => {}
+                    ord => return ord,
+                }
+                a = a.add(1);
+                b = b.add(1);
+            }
+        }
+        Ordering::Equal
+    }
+
+    /// Compares two wide strings for equality in time that depends only on the
+    /// length of the longer string.
+    ///
+    /// Unlike the [`PartialEq`] implementation, this does not stop at the
+    /// first difference, so the time taken does not reveal where two strings
+    /// differ. This is intended for comparing secrets, such as tokens stored
+    /// as wide strings. Neither string is read past its NUL-terminator: once
+    /// the shorter string ends, its NUL-terminator is compared against the
+    /// rest of the longer string.
+    ///
+    /// This is a best-effort guarantee, since the compiler is free to
+    /// optimize the comparison.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// let token = wcstr!(u16, "s3cr3t");
+    ///
+    /// assert!(token.ct_eq(wcstr!(u16, "s3cr3t")));
+    /// assert!(!token.ct_eq(wcstr!(u16, "s3cr3T")));
+    /// assert!(!token.ct_eq(wcstr!(u16, "s3cr3t!")));
+    /// assert!(!token.ct_eq(wcstr!(u16, "")));
+    /// ```
+    pub fn ct_eq(&self, other: &WCStr<T>) -> bool {
+        let mut a = self.as_ptr();
+        let mut b = other.as_ptr();
+
+        let mut diff = 0u8;
+        // SAFETY: Safe references to `WCStr<T>` can only exist if they point to
+        //         memory that has a NUL-terminator, and neither pointer is
+        //         advanced past its NUL-terminator.
+        unsafe {
+            loop {
+                let (u, v) = (*a, *b);
+                diff |= (u != v) as u8;
+
+                let (a_end, b_end) = (u == T::NUL, v == T::NUL);
+                if a_end & b_end {
+                    break;
+                }
+                a = a.add(!a_end as usize);
+                b = b.add(!b_end as usize);
+            }
+        }
+        diff == 0
+    }
+
+    /// Returns the number of leading wide characters shared by two wide
+    /// strings.
+    ///
+    /// Both strings are walked in lockstep until they differ or either one
+    /// ends, so neither length needs to be calculated up front.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// assert_eq!(wcstr!(u16, "foobar").common_prefix_len(wcstr!(u16, "fooqux")), 3);
+    /// assert_eq!(wcstr!(u16, "foo").common_prefix_len(wcstr!(u16, "foobar")), 3);
+    /// assert_eq!(wcstr!(u16, "foo").common_prefix_len(wcstr!(u16, "bar")), 0);
+    /// ```
+    pub fn common_prefix_len(&self, other: &WCStr<T>) -> usize {
+        let a = self.as_ptr();
+        let b = other.as_ptr();
+
+        let mut n = 0;
+        // SAFETY: Safe references to `WCStr<T>` can only exist if they point to
+        //         memory that has a NUL-terminator, and neither pointer is
+        //         advanced past the first NUL-terminator found.
+        unsafe {
+            while *a.add(n) != T::NUL && *a.add(n) == *b.add(n) {
+                n += 1;
+            }
+        }
+        n
+    }
+
+    /// Returns the number of leading wide characters shared by two wide
+    /// strings, without splitting an encoded `char` in either string.
+    ///
+    /// This is the same as [`WCStr::common_prefix_len`], except that the
+    /// prefix never ends between the two halves of a UTF-16 surrogate pair.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// // U+1D11E and U+1D11F share a leading surrogate.
+    /// let a = wcstr!(u16, "a𝄞");
+    /// let b = wcstr!(u16, "a𝄟");
+    ///
+    /// assert_eq!(a.common_prefix_len(b), 2);
+    /// assert_eq!(a.common_char_prefix_len(b), 1);
+    /// ```
+    pub fn common_char_prefix_len(&self, other: &WCStr<T>) -> usize {
+        let n = self.common_prefix_len(other);
+
+        // SAFETY: Both strings have at least `n` wide characters before their
+        //         NUL-terminators, so the first `n + 1` are readable.
+        let (a, b) = unsafe {
+            (
+                slice::from_raw_parts(self.as_ptr(), n + 1),
+                slice::from_raw_parts(other.as_ptr(), n + 1),
+            )
+        };
+        if T::is_char_boundary(a, n) && T::is_char_boundary(b, n) {
+            n
+        } else {
+            // Only the leading surrogate of a pair can be in the prefix.
+            n - 1
+        }
+    }
+
+    /// Returns the number of bytes in the UTF-8 encoding of a wide string,
+    /// counting invalid data as
+    /// [the replacement character (`U+FFFD`)][U+FFFD].
+    ///
+    /// This is the length of the output of [`WCStr::to_string_lossy`], and
+    /// allows a buffer to be sized exactly without allocating.
+    ///
+    /// [U+FFFD]: core::char::REPLACEMENT_CHARACTER
+    ///
+    /// > **Note**: This operation is not zero-cost, requiring the whole string
+    /// > to be decoded.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCStr;
+    ///
+    /// // ab<invalid><nul>
+    /// let v: &[u16] = &[0x0061, 0x0062, 0xD834, 0x0000];
+    /// let s = WCStr::from_slice_with_nul(v).unwrap();
+    ///
+    /// assert_eq!(s.utf8_len(), 5);
+    /// ```
+    #[inline]
+    pub fn utf8_len(&self) -> usize {
+        self.chars()
+            .map(|c| c.unwrap_or(core::char::REPLACEMENT_CHARACTER).len_utf8())
+            .sum()
+    }
+
+    /// Returns the number of bytes in the UTF-8 encoding of a wide string, or
+    /// an error if the wide string cannot be decoded.
+    ///
+    /// This is the buffer size required by [`WCStr::encode_utf8_into`].
+    ///
+    /// > **Note**: This operation is not zero-cost, requiring the whole string
+    /// > to be decoded.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::{wcstr, WCStr};
+    ///
+    /// assert_eq!(wcstr!(u16, "🦀rust").try_utf8_len(), Ok(8));
+    ///
+    /// // ab<invalid><nul>
+    /// let v: &[u16] = &[0x0061, 0x0062, 0xD834, 0x0000];
+    /// let s = WCStr::from_slice_with_nul(v).unwrap();
+    ///
+    /// assert_eq!(s.try_utf8_len().unwrap_err().code(), 0xD834);
+    /// ```
+    #[inline]
+    pub fn try_utf8_len(&self) -> Result<usize, DecodeWideError<T>> {
+        self.chars().map(|c| c.map(char::len_utf8)).sum()
+    }
+
+    /// Encodes a wide string as UTF-8 into a byte buffer, returning the number
+    /// of bytes written.
+    ///
+    /// No allocation takes place, and a [`char`] is never partially written
+    /// at the end of the buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the wide string cannot be decoded, or if `buf` is
+    /// too small to hold the encoded string. In the latter case the error
+    /// reports the number of bytes required, see
+    /// [`EncodeUtf8Error::required_len`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// let mut buf = [0u8; 16];
+    /// let len = wcstr!(u16, "🦀rust").encode_utf8_into(&mut buf).unwrap();
+    ///
+    /// assert_eq!(&buf[..len], "🦀rust".as_bytes());
+    /// ```
+    ///
+    /// A buffer that is too small is an error:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// let mut buf = [0u8; 4];
+    /// let err = wcstr!(u32, "🦀rust").encode_utf8_into(&mut buf).unwrap_err();
+    ///
+    /// assert_eq!(err.required_len(), Some(8));
+    /// assert_eq!(err.decode_error(), None);
+    /// ```
+    pub fn encode_utf8_into(&self, buf: &mut [u8]) -> Result<usize, EncodeUtf8Error<T>> {
+        let mut written = 0;
+        let mut chars = self.chars();
+
+        while let Some(c) = chars.next() {
+            let c = c.map_err(EncodeUtf8Error::decode)?;
+            let len = c.len_utf8();
+
+            match buf.get_mut(written..written + len) {
+                Some(dst) => {
+                    c.encode_utf8(dst);
+                    written += len;
+                }
+                None => {
+                    // Decode the rest of the string to find the required length.
+                    let mut required = written + len;
+                    for c in chars {
+                        required += c.map_err(EncodeUtf8Error::decode)?.len_utf8();
+                    }
+                    return Err(EncodeUtf8Error::buffer_too_small(required));
+                }
+            }
+        }
+
+        Ok(written)
+    }
+
+    /// Writes a wide string as UTF-8 into a [`fmt::Write`] sink, replacing
+    /// invalid data with [the replacement character (`U+FFFD`)][U+FFFD].
+    ///
+    /// The string is decoded one [`char`] at a time, so no allocation takes
+    /// place.
+    ///
+    /// [U+FFFD]: char::REPLACEMENT_CHARACTER
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCStr;
+    ///
+    /// // 🦀<invalid>!<nul>
+    /// let v: &[u32] = &[0x0001_F980, 0x0011_0000, 0x0000_0021, 0x0000_0000];
+    /// let s = WCStr::from_slice_with_nul(v).unwrap();
+    ///
+    /// let mut out = String::new();
+    /// s.write_utf8_lossy(&mut out).unwrap();
+    ///
+    /// assert_eq!(out, "🦀\u{FFFD}!");
+    /// ```
+    pub fn write_utf8_lossy<W: fmt::Write>(&self, w: &mut W) -> fmt::Result {
+        for r in self.chars() {
+            w.write_char(r.unwrap_or(char::REPLACEMENT_CHARACTER))?;
+        }
+        Ok(())
+    }
+
+    /// Returns an object that implements [`Display`] for safely printing a
+    /// wide string that may contain invalid data.
+    ///
+    /// Invalid data is replaced with
+    /// [the replacement character (`U+FFFD`)][U+FFFD], as in
+    /// [`WCStr::to_string_lossy`], but without allocating.
+    ///
+    /// [`Display`]: core::fmt::Display
+    /// [U+FFFD]: char::REPLACEMENT_CHARACTER
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCStr;
+    ///
+    /// // 𝄞mus<invalid>ic<nul>
+    /// let v: &[u16] = &[0xD834, 0xDD1E, 0x006d, 0x0075, 0x0073, 0xDD1E, 0x0069, 0x0063, 0x0000];
+    /// let s = WCStr::from_slice_with_nul(v).unwrap();
+    ///
+    /// assert_eq!(format!("{}", s.display()), "𝄞mus\u{FFFD}ic");
+    /// ```
+    ///
+    /// Width, alignment, fill and precision are honored as for [`str`],
+    /// measured in decoded `char`s. This does not require `alloc`:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// let s = wcstr!(u16, "🦀ab");
+    ///
+    /// assert_eq!(format!("[{:5}]", s.display()), "[🦀ab  ]");
+    /// assert_eq!(format!("[{:>5}]", s.display()), "[  🦀ab]");
+    /// assert_eq!(format!("[{:-^7}]", s.display()), "[--🦀ab--]");
+    /// assert_eq!(format!("[{:>4.2}]", s.display()), "[  🦀a]");
+    /// ```
+    #[inline]
+    pub fn display(&self) -> Display<'_, T> {
+        Display::new(self)
+    }
+
+    /// Returns an object that implements [`Display`] for printing a wide
+    /// string with characters escaped as by [`char::escape_debug`].
+    ///
+    /// Invalid data is printed as a `\u{...}` escape of the raw wide
+    /// character.
+    ///
+    /// [`Display`]: core::fmt::Display
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCStr;
+    ///
+    /// // "a"\t<invalid><nul>
+    /// let v: &[u16] = &[0x0022, 0x0061, 0x0022, 0x0009, 0xDD1E, 0x0000];
+    /// let s = WCStr::from_slice_with_nul(v).unwrap();
+    ///
+    /// assert_eq!(format!("{}", s.display_escaped()), r#"\"a\"\t\u{dd1e}"#);
+    /// ```
+    #[inline]
+    pub fn display_escaped(&self) -> DisplayEscaped<'_, T> {
+        DisplayEscaped::new(self)
+    }
+
+    /// Binary searches a sorted slice of wide strings for `target`.
+    ///
+    /// The slice must be sorted by the [`Ord`] implementation of `WCStr`,
+    /// which compares wide characters in order. If the slice is not sorted the
+    /// result is unspecified and meaningless.
+    ///
+    /// If `target` is found then [`Result::Ok`] is returned, containing the
+    /// index of the matching element. If there are multiple matches any one of
+    /// them may be returned. If `target` is not found then [`Result::Err`] is
+    /// returned, containing the index where a matching element could be
+    /// inserted while maintaining sorted order.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::{wcstr, WCStr};
+    ///
+    /// let haystack = [
+    ///     wcstr!(u16, "apple"),
+    ///     wcstr!(u16, "banana"),
+    ///     wcstr!(u16, "cherry"),
+    ///     wcstr!(u16, "date"),
+    /// ];
+    ///
+    /// assert_eq!(WCStr::binary_search_in(&haystack, wcstr!(u16, "cherry")), Ok(2));
+    /// assert_eq!(WCStr::binary_search_in(&haystack, wcstr!(u16, "blueberry")), Err(2));
+    /// assert_eq!(WCStr::binary_search_in(&haystack, wcstr!(u16, "zucchini")), Err(4));
+    /// ```
+    #[inline]
+    pub fn binary_search_in(haystack: &[&WCStr<T>], target: &WCStr<T>) -> Result<usize, usize> {
+        haystack.binary_search_by(|s| (*s).cmp(target))
+    }
+
+    /// Checks if all wide characters in a wide string are within the ASCII
+    /// range.
+    ///
+    /// > **Note**: This operation is not zero-cost, requiring iteration through
+    /// > all bytes of the string to calculate the length.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// assert!(wcstr!(u16, "hello!\n").is_ascii());
+    /// assert!(wcstr!(u32, "").is_ascii());
+    ///
+    /// assert!(!wcstr!(u16, "grüße").is_ascii());
+    /// assert!(!wcstr!(i32, "🦀").is_ascii());
+    /// ```
+    #[inline]
+    pub fn is_ascii(&self) -> bool {
+        self.to_slice().iter().all(|&u| u.is_ascii())
+    }
+
+    /// Checks that two wide strings are an ASCII case-insensitive match.
+    ///
+    /// Same as `to_ascii_lowercase(a) == to_ascii_lowercase(b)`, but without
+    /// allocating and copying temporaries. Code units outside of the ASCII
+    /// range, including surrogates, are compared exactly.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// assert!(wcstr!(u16, "FILE.TXT").eq_ignore_ascii_case(wcstr!(u16, "file.txt")));
+    /// assert!(wcstr!(u32, "FILE.TXT").eq_ignore_ascii_case(wcstr!(u32, "file.txt")));
+    ///
+    /// assert!(!wcstr!(u16, "FILE.TXT").eq_ignore_ascii_case(wcstr!(u16, "file.txt.bak")));
+    /// assert!(!wcstr!(u32, "Ä").eq_ignore_ascii_case(wcstr!(u32, "ä")));
+    /// ```
+    pub fn eq_ignore_ascii_case(&self, other: &WCStr<T>) -> bool {
+        let mut a = self.as_ptr();
+        let mut b = other.as_ptr();
+
+        // SAFETY: Safe references to `WCStr<T>` can only exist if they point to
+        //         memory that has a NUL-terminator, and neither pointer is
+        //         advanced past the first NUL-terminator found.
+        unsafe {
+            loop {
+                let (u, v) = (*a, *b);
+                if u.to_ascii_lowercase() != v.to_ascii_lowercase() {
+                    return false;
+                }
+                // The units are equal, so both strings end here.
+                if u == T::NUL {
+                    return true;
+                }
+                a = a.add(1);
+                b = b.add(1);
+            }
+        }
+    }
+
+    /// Checks that a wide string and a string slice are an ASCII
+    /// case-insensitive match.
+    ///
+    /// The wide string is decoded one [`char`] at a time, so no allocation
+    /// takes place. A wide string that cannot be decoded never matches.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// assert!(wcstr!(u16, "Content-Type").eq_ignore_ascii_case_str("content-type"));
+    /// assert!(wcstr!(u32, "Content-Type").eq_ignore_ascii_case_str("CONTENT-TYPE"));
+    ///
+    /// assert!(!wcstr!("Content-Type").eq_ignore_ascii_case_str("content-length"));
+    /// ```
+    pub fn eq_ignore_ascii_case_str(&self, other: &str) -> bool {
+        let mut chars = self.chars();
+        let mut other = other.chars();
+
+        loop {
+            match (chars.next(), other.next()) {
+                (None, None) => return true,
+                (Some(Ok(a)), Some(b)) if a.eq_ignore_ascii_case(&b) => {}
+                _ => return false,
+            }
+        }
+    }
+
+    /// Checks that a wide string is equal to a string slice, replacing any
+    /// invalid wide characters with [`U+FFFD REPLACEMENT CHARACTER`][U+FFFD].
+    ///
+    /// This compares the same way as [`WCStr::to_string_lossy`] would, but
+    /// without allocating. An invalid wide character therefore matches a
+    /// literal replacement character in `other`.
+    ///
+    /// [U+FFFD]: core::char::REPLACEMENT_CHARACTER
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::{wcstr, WCStr};
+    ///
+    /// assert!(wcstr!(u16, "hello").eq_str_lossy("hello"));
+    /// assert!(!wcstr!(u16, "hello").eq_str_lossy("world"));
+    ///
+    /// // a<lone surrogate><nul>
+    /// let s = WCStr::<u16>::from_slice_with_nul(&[0x61, 0xD800, 0x00]).unwrap();
+    ///
+    /// assert!(s.eq_str_lossy("a\u{FFFD}"));
+    /// ```
+    pub fn eq_str_lossy(&self, other: &str) -> bool {
+        self.chars()
+            .map(|c| c.unwrap_or(core::char::REPLACEMENT_CHARACTER))
+            .eq(other.chars())
+    }
+
+    /// Checks that a wide string is equal to a string slice.
+    ///
+    /// Unlike [`WCStr::eq_str_lossy`], a wide string containing invalid wide
+    /// characters is never equal to any string slice.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::{wcstr, WCStr};
+    ///
+    /// assert!(wcstr!(u16, "hello").eq_str_strict("hello"));
+    /// assert!(!wcstr!(u16, "hello").eq_str_strict("world"));
+    ///
+    /// // a<lone surrogate><nul>
+    /// let s = WCStr::<u16>::from_slice_with_nul(&[0x61, 0xD800, 0x00]).unwrap();
+    ///
+    /// assert!(!s.eq_str_strict("a\u{FFFD}"));
+    /// ```
+    pub fn eq_str_strict(&self, other: &str) -> bool {
+        let mut chars = self.chars();
+        let mut other = other.chars();
+
+        loop {
+            match (chars.next(), other.next()) {
+                (None, None) => return true,
+                (Some(Ok(a)), Some(b)) if a == b => {}
+                _ => return false,
+            }
+        }
+    }
+
+    /// Returns `true` if the contents of a wide string, excluding the
+    /// NUL-terminator, are exactly `units`.
+    ///
+    /// `units` is treated as the exact expected content, so if it contains a
+    /// NUL character anywhere, including at the end, the result is `false`.
+    /// Use [`WCStr::eq_units_with_nul`] to compare including the
+    /// NUL-terminator. The string is walked in lockstep with `units`, so its
+    /// length is never calculated.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// let s = wcstr!(u16, "hi");
+    ///
+    /// assert!(s.eq_units(&[0x0068, 0x0069]));
+    /// assert!(!s.eq_units(&[0x0068, 0x0069, 0x0000]));
+    /// assert!(!s.eq_units(&[0x0068]));
+    /// ```
+    pub fn eq_units(&self, units: &[T]) -> bool {
+        let mut ptr = self.as_ptr();
+
+        // SAFETY: Safe references to `WCStr<T>` can only exist if they point to
+        //         memory that has a NUL-terminator, and `ptr` is not advanced
+        //         past it.
+        unsafe {
+            for &u in units {
+                let v = *ptr;
+                if v == T::NUL || v != u {
+                    return false;
+                }
+                ptr = ptr.add(1);
+            }
+            *ptr == T::NUL
+        }
+    }
+
+    /// Returns `true` if the contents of a wide string, including the
+    /// NUL-terminator, are exactly `units`.
+    ///
+    /// This is the same as [`WCStr::eq_units`], except that `units` must end
+    /// with the NUL-terminator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// let s = wcstr!(u16, "hi");
+    ///
+    /// assert!(s.eq_units_with_nul(&[0x0068, 0x0069, 0x0000]));
+    /// assert!(!s.eq_units_with_nul(&[0x0068, 0x0069]));
+    /// assert!(!s.eq_units_with_nul(&[0x0068, 0x0069, 0x0000, 0x0000]));
+    /// ```
+    pub fn eq_units_with_nul(&self, units: &[T]) -> bool {
+        match units.split_last() {
+            Some((&last, rest)) if last == T::NUL => self.eq_units(rest),
+            _ => false,
+        }
+    }
+}
+
+impl WCStr<WChar> {
+    /// Returns the inner pointer to this C-style wide string as a pointer to
+    /// the system wide character type, `wchar_t`.
+    ///
+    /// This method only exists for strings backed by [`WChar`], so passing a
+    /// string of a different backing type to a C function expecting a
+    /// `wchar_t` pointer is a compile error rather than a silent cast.
+    ///
+    /// See [`WCStr::as_ptr`] for the lifetime requirements of the returned
+    /// pointer.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::wcstr;
+    ///
+    /// let s = wcstr!("Rust");
+    /// let len = unsafe { libc::wcslen(s.as_wide_cstr_ptr()) };
+    ///
+    /// assert_eq!(len, 4);
+    /// ```
+    #[inline]
+    pub const fn as_wide_cstr_ptr(&self) -> *const WChar {
+        self.as_ptr()
+    }
+}
+
+macro_rules! impl_as_units {
+    ($($method:ident -> $unit:ident for $($ty:ident)*;)*) => {
+        $($(
+            static_assert!(mem::size_of::<$ty>() == mem::size_of::<$unit>());
+            static_assert!(mem::align_of::<$ty>() == mem::align_of::<$unit>());
+
+            impl WCStr<$ty> {
+                #[doc = concat!("Converts a `WCStr` into a slice of `", stringify!($unit), "` code units, excluding the NUL-terminator.")]
+                ///
+                /// The wide characters are reinterpreted by their bit pattern,
+                /// giving a canonical unsigned view for use with functions from
+                /// the standard library.
+                ///
+                /// > **Note**: This operation is not zero-cost, requiring iteration
+                /// > through all bytes of the string to calculate the length.
+                ///
+                /// # Examples
+                ///
+                /// Basic usage:
+                ///
+                /// ```
+                /// use thicc::wcstr;
+                ///
+                #[doc = concat!("let s = wcstr!(", stringify!($ty), ", \"🦀\");")]
+                #[doc = concat!("let units: &[", stringify!($unit), "] = s.", stringify!($method), "();")]
+                ///
+                /// assert_eq!(units.len(), s.len());
+                /// ```
+                #[inline]
+                pub fn $method(&self) -> &[$unit] {
+                    let units = self.to_slice();
+                    // SAFETY: `$ty` has the same size and alignment as `$unit`,
+                    //         and both are plain integers.
+                    unsafe { slice::from_raw_parts(units.as_ptr() as *const $unit, units.len()) }
+                }
+            }
+        )*)*
+    };
+}
+impl_as_units! {
+    as_utf16_units -> u16 for u16 i16;
+    as_utf32_units -> u32 for u32 i32;
+}
+
+macro_rules! impl_as_valid_utf16_bytes {
+    ($($ty:ident)*) => {
+        $(
+            impl WCStr<$ty> {
+                /// Converts a `WCStr` into a slice of bytes, after checking that
+                /// it is well-formed UTF-16 with no unpaired surrogates.
+                ///
+                /// The bytes are as returned by [`WCStr::as_bytes`], so
+                /// consumers of the slice can assume it is valid UTF-16 in the
+                /// native endianness.
+                ///
+                /// > **Note**: This operation is not zero-cost, requiring the
+                /// > whole string to be decoded.
+                ///
+                /// # Errors
+                ///
+                /// Returns the first wide character that could not be decoded.
+                ///
+                /// # Examples
+                ///
+                /// Basic usage:
+                ///
+                /// ```
+                /// use thicc::{wcstr, WCStr};
+                ///
+                #[doc = concat!("let s = wcstr!(", stringify!($ty), ", \"🦀\");")]
+                /// assert_eq!(s.as_valid_utf16_bytes().unwrap(), s.as_bytes());
+                ///
+                /// // a<invalid><nul>
+                #[doc = concat!("let v: &[", stringify!($ty), "] = &[0x0061, 0xDC00u16 as ", stringify!($ty), ", 0x0000];")]
+                /// let s = WCStr::from_slice_with_nul(v).unwrap();
+                ///
+                #[doc = concat!("assert_eq!(s.as_valid_utf16_bytes().unwrap_err().code(), 0xDC00u16 as ", stringify!($ty), ");")]
+                /// ```
+                pub fn as_valid_utf16_bytes(&self) -> Result<&[u8], DecodeWideError<$ty>> {
+                    self.validate().map_err(|(_, e)| e)?;
+                    Ok(self.as_bytes())
+                }
+            }
+        )*
+    };
+}
+impl_as_valid_utf16_bytes!(u16 i16);
+
+macro_rules! impl_from_slice_with_nul_const {
+    ($($ty:ident)*) => {
+        $(
+            impl WCStr<$ty> {
+                /// Creates a `WCStr` from a slice of wide characters with a
+                /// NUL-terminator, checking the slice in a `const` context.
+                ///
+                /// This is used by the [`wcstr!`] macro, so that an invalid
+                /// string literal is a compile error.
+                ///
+                /// [`wcstr!`]: crate::wcstr
+                ///
+                /// # Panics
+                ///
+                /// Panics if `slice` is not NUL-terminated or contains an
+                /// interior NUL character. When evaluated in a `const` context
+                /// this is a compile error.
+                ///
+                /// # Examples
+                ///
+                /// Basic usage:
+                ///
+                /// ```
+                /// use thicc::WCStr;
+                ///
+                #[doc = concat!("const S: &WCStr<", stringify!($ty), "> = WCStr::<", stringify!($ty), ">::from_slice_with_nul_const(&[0x68, 0x69, 0x00]);")]
+                ///
+                /// assert_eq!(S.to_slice(), &[0x68, 0x69]);
+                /// ```
+                ///
+                /// An interior NUL character fails to compile:
+                ///
+                /// ```compile_fail
+                /// use thicc::WCStr;
+                ///
+                #[doc = concat!("const S: &WCStr<", stringify!($ty), "> = WCStr::<", stringify!($ty), ">::from_slice_with_nul_const(&[0x68, 0x00, 0x69, 0x00]);")]
+                /// ```
+                pub const fn from_slice_with_nul_const(slice: &[$ty]) -> &WCStr<$ty> {
+                    let last = match slice.len().checked_sub(1) {
+                        Some(last) if slice[last] == 0 => last,
+                        _ => panic!("wide string is not NUL-terminated"),
+                    };
+
+                    let mut i = 0;
+                    while i < last {
+                        if slice[i] == 0 {
+                            panic!("wide string contains an interior NUL character");
+                        }
+                        i += 1;
+                    }
+
+                    // SAFETY: `slice` is NUL-terminated and contains no interior
+                    //         NUL characters.
+                    unsafe { WCStr::from_slice_with_nul_unchecked(slice) }
+                }
+            }
+        )*
+    };
+}
+impl_from_slice_with_nul_const!(u16 u32 i16 i32);
+
+impl<T: Wide> PartialEq for WCStr<T> {
+    #[inline]
+    fn eq(&self, other: &WCStr<T>) -> bool {
+        let mut a = self.as_ptr();
+        let mut b = other.as_ptr();
+
+        if a == b {
+            return true;
+        }
+
+        // Walk both strings in lockstep, rather than computing both lengths up
+        // front.
+        //
+        // SAFETY: Safe references to `WCStr<T>` can only exist if they point to
+        //         memory that has a NUL-terminator, and neither pointer is
+        //         advanced past the first NUL-terminator found.
+        unsafe {
+            loop {
+                let u = *a;
+                if u != *b {
+                    return false;
+                }
+                // The units are equal, so both strings end here.
+                if u == T::NUL {
+                    return true;
+                }
+                a = a.add(1);
+                b = b.add(1);
+            }
+        }
+    }
+}
+
+impl<T: Wide> Eq for WCStr<T> {}
+
+macro_rules! impl_partial_eq_signedness {
+    ($($a:ident <=> $b:ident),*) => {
+        $(
+            static_assert!(mem::size_of::<$a>() == mem::size_of::<$b>());
+            static_assert!(mem::align_of::<$a>() == mem::align_of::<$b>());
+
+            #[doc = concat!("Compares a `WCStr<", stringify!($a), ">` with a `WCStr<", stringify!($b), ">` by the bit patterns of their wide characters.")]
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::wcstr;
+            ///
+            #[doc = concat!("assert!(wcstr!(", stringify!($a), ", \"x\") == wcstr!(", stringify!($b), ", \"x\"));")]
+            #[doc = concat!("assert!(wcstr!(", stringify!($a), ", \"x\") != wcstr!(", stringify!($b), ", \"y\"));")]
+            /// ```
+            impl PartialEq<WCStr<$b>> for WCStr<$a> {
+                #[inline]
+                fn eq(&self, other: &WCStr<$b>) -> bool {
+                    // SAFETY: `$a` and `$b` have the same size and alignment,
+                    //         so `other` is also a valid `WCStr<$a>`.
+                    self == unsafe { &*(other as *const WCStr<$b> as *const WCStr<$a>) }
+                }
+            }
+
+            #[doc = concat!("Compares a `WCStr<", stringify!($b), ">` with a `WCStr<", stringify!($a), ">` by the bit patterns of their wide characters.")]
+            impl PartialEq<WCStr<$a>> for WCStr<$b> {
+                #[inline]
+                fn eq(&self, other: &WCStr<$a>) -> bool {
+                    other == self
+                }
+            }
+        )*
+    };
+}
+impl_partial_eq_signedness!(u16 <=> i16, u32 <=> i32);
+
+impl<T: Wide> PartialOrd for WCStr<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &WCStr<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Wide> Ord for WCStr<T> {
+    #[inline]
+    fn cmp(&self, other: &WCStr<T>) -> Ordering {
+        self.to_slice().cmp(other.to_slice())
+    }
+}
+
+/// Finds the position of the first NUL character within the first `max` wide
+/// characters at `ptr`.
+///
+/// # Safety
+///
+/// The memory referenced by `ptr` must be valid for reads up to and including
+/// the first NUL character, or for `max` wide characters if there is no NUL
+/// character within that bound.
+unsafe fn find_nul<T: Wide>(ptr: *const T, max: usize) -> Option<usize> {
+    (0..max).find(|&i| *ptr.add(i) == T::NUL)
+}
+
+impl<T: Wide> Hash for WCStr<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_wide_str(self, state);
+    }
+}
+
+/// Hashes the contents of a wide string.
+///
+/// This is shared by the [`Hash`] implementations of all wide string types, so
+/// that equal strings hash identically regardless of ownership.
+pub(crate) fn hash_wide_str<T: Wide, H: Hasher>(s: &WCStr<T>, state: &mut H) {
+    // Number of wide characters fed to the hasher at a time.
+    const CHUNK: usize = 64;
+
+    // Feed the string to the hasher in chunks as the NUL-terminator is
+    // searched for, rather than computing the length up front. The
+    // NUL-terminator itself is hashed in place of a length prefix.
+    let mut ptr = s.as_ptr();
+
+    // SAFETY: Safe references to `WCStr<T>` can only exist if they point to
+    //         memory that has a NUL-terminator, and no chunk extends past
+    //         the first NUL-terminator found.
+    unsafe {
+        loop {
+            let mut n = 0;
+            while n < CHUNK && *ptr.add(n) != T::NUL {
+                n += 1;
+            }
+
+            if n < CHUNK {
+                T::hash_slice(slice::from_raw_parts(ptr, n + 1), state);
+                return;
+            }
+
+            T::hash_slice(slice::from_raw_parts(ptr, CHUNK), state);
+            ptr = ptr.add(CHUNK);
+        }
+    }
+}
+
+/// Allows generic APIs to accept a borrowed or owned wide string.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use thicc::{wcstr, WCStr, WCString};
+///
+/// fn count<S: AsRef<WCStr<u16>>>(s: S) -> usize {
+///     s.as_ref().len()
+/// }
+///
+/// let mut owned = WCString::new();
+/// owned.push_str("owned");
+///
+/// assert_eq!(count(wcstr!(u16, "borrowed")), 8);
+/// assert_eq!(count(&owned), 5);
+/// assert_eq!(count(owned), 5);
+/// ```
+impl<T: Wide> AsRef<WCStr<T>> for WCStr<T> {
+    #[inline]
+    fn as_ref(&self) -> &WCStr<T> {
+        self
+    }
+}
+
+/// Iterates over the [`char`]s of a wide string, as with [`WCStr::chars`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use thicc::WCStr;
+///
+/// // a<invalid>b<nul>
+/// let s = WCStr::<u16>::from_slice_with_nul(&[0x0061, 0xDC00, 0x0062, 0x0000]).unwrap();
+///
+/// let mut out = String::new();
+/// for c in s {
+///     match c {
+///         Ok(c) => out.push(c),
+///         Err(e) => out.push_str(&format!("<{:X}>", e.code())),
+///     }
+/// }
+///
+/// assert_eq!(out, "a<DC00>b");
+/// ```
+impl<'a, T: Wide> IntoIterator for &'a WCStr<T> {
+    type Item = Result<char, DecodeWideError<T>>;
+    type IntoIter = Chars<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Chars<'a, T> {
+        self.chars()
+    }
+}
+
+macro_rules! impl_default {
+    ($($ty:ident)*) => {
+        $(
+            /// Creates an empty `WCStr`.
+            impl Default for &WCStr<$ty> {
+                #[inline]
+                fn default() -> Self {
+                    static EMPTY: [$ty; 1] = [0];
+                    // SAFETY: A lone NUL-terminator is a valid empty string.
+                    unsafe { WCStr::from_slice_with_nul_unchecked(&EMPTY) }
+                }
+            }
+        )*
+    };
+}
+impl_default!(u16 u32 i16 i32);
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "alloc")] {
+        use crate::alloc::{Box, Cow, String, ToOwned, Vec};
+        use crate::wcstring::WCString;
+
+        impl<T: Wide> WCStr<T> {
+            /// Decodes a wide character string into a [`String`], replacing
+            /// invalid data with [the replacement character (`U+FFFD`)][U+FFFD].
+            ///
+            /// [U+FFFD]: char::REPLACEMENT_CHARACTER
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::WCStr;
+            ///
+            /// // 𝄞mus<invalid>ic<invalid>
+            /// let v: &[u16] = &[
+            ///     0xD834, 0xDD1E, 0x006d, 0x0075, 0x0073, 0xDD1E, 0x0069, 0x0063, 0xD834, 0x0000,
+            /// ];
+            /// let s = WCStr::from_slice_with_nul(v).unwrap();
+            ///
+            /// assert_eq!("𝄞mus\u{FFFD}ic\u{FFFD}", s.to_string_lossy());
+            /// ```
+            #[inline]
+            pub fn to_string_lossy(&self) -> String {
+                self.chars().map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER)).collect()
+            }
+
+            /// Decodes a wide character string into a [`String`], returning
+            /// the first wide character that could not be decoded as an error.
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::{wcstr, WCStr};
+            ///
+            /// assert_eq!(wcstr!(u16, "𝄞music").decode().unwrap(), "𝄞music");
+            ///
+            /// // mus<invalid>ic<invalid><nul>
+            /// let v: &[u16] = &[0x006d, 0x0075, 0x0073, 0xDD1E, 0x0069, 0x0063, 0xD834, 0x0000];
+            /// let s = WCStr::from_slice_with_nul(v).unwrap();
+            ///
+            /// assert_eq!(s.decode().unwrap_err().code(), 0xDD1E);
+            /// ```
+            #[inline]
+            pub fn decode(&self) -> Result<String, DecodeWideError<T>> {
+                self.chars().collect()
+            }
+
+            /// Decodes a wide character string into a [`String`], replacing
+            /// invalid data with [the replacement character (`U+FFFD`)][U+FFFD].
+            ///
+            /// This is the same as [`WCStr::to_string_lossy`], named as the
+            /// infallible counterpart to [`WCStr::decode`].
+            ///
+            /// [U+FFFD]: char::REPLACEMENT_CHARACTER
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::WCStr;
+            ///
+            /// // mus<invalid>ic<nul>
+            /// let v: &[u16] = &[0x006d, 0x0075, 0x0073, 0xDD1E, 0x0069, 0x0063, 0x0000];
+            /// let s = WCStr::from_slice_with_nul(v).unwrap();
+            ///
+            /// assert_eq!(s.decode_lossy(), "mus\u{FFFD}ic");
+            /// ```
+            #[inline]
+            pub fn decode_lossy(&self) -> String {
+                self.to_string_lossy()
+            }
+
+            /// Decodes a wide character string into a [`Box<str>`], replacing
+            /// invalid data with [the replacement character (`U+FFFD`)][U+FFFD].
+            ///
+            /// [U+FFFD]: char::REPLACEMENT_CHARACTER
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::WCStr;
+            ///
+            /// // 𝄞mus<invalid>ic<nul>
+            /// let v: &[u16] = &[0xD834, 0xDD1E, 0x006d, 0x0075, 0x0073, 0xDD1E, 0x0069, 0x0063, 0x0000];
+            /// let s = WCStr::from_slice_with_nul(v).unwrap();
+            ///
+            /// let boxed: Box<str> = s.to_boxed_str_lossy();
+            /// assert_eq!(&*boxed, "𝄞mus\u{FFFD}ic");
+            /// ```
+            #[inline]
+            pub fn to_boxed_str_lossy(&self) -> Box<str> {
+                self.to_string_lossy().into_boxed_str()
+            }
+
+            /// Decodes a wide character string into a [`Box<str>`], returning an
+            /// error if the string contains invalid data.
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::{wcstr, WCStr};
+            ///
+            /// let boxed: Box<str> = wcstr!(u32, "🦀 rust").try_to_boxed_str().unwrap();
+            /// assert_eq!(&*boxed, "🦀 rust");
+            ///
+            /// // 𝄞mus<invalid>ic<nul>
+            /// let v: &[u16] = &[0xD834, 0xDD1E, 0x006d, 0x0075, 0x0073, 0xDD1E, 0x0069, 0x0063, 0x0000];
+            /// let s = WCStr::from_slice_with_nul(v).unwrap();
+            ///
+            /// assert_eq!(s.try_to_boxed_str().unwrap_err().code(), 0xDD1E);
+            /// ```
+            #[inline]
+            pub fn try_to_boxed_str(&self) -> Result<Box<str>, DecodeWideError<T>> {
+                self.decode().map(String::into_boxed_str)
+            }
+
+            /// Decodes a wide character string into a [`Box<[char]>`][Box],
+            /// replacing invalid data with
+            /// [the replacement character (`U+FFFD`)][U+FFFD].
+            ///
+            /// The box is sized exactly, for code that indexes the decoded
+            /// [`char`]s repeatedly.
+            ///
+            /// [U+FFFD]: char::REPLACEMENT_CHARACTER
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::WCStr;
+            ///
+            /// // 🦀<invalid><nul>
+            /// let v: &[u16] = &[0xD83E, 0xDD80, 0xDC00, 0x0000];
+            /// let s = WCStr::from_slice_with_nul(v).unwrap();
+            ///
+            /// let chars: Box<[char]> = s.to_boxed_chars_lossy();
+            /// assert_eq!(&*chars, ['🦀', '\u{FFFD}']);
+            /// ```
+            pub fn to_boxed_chars_lossy(&self) -> Box<[char]> {
+                let mut out = Vec::new();
+                self.decode_lossy_into(&mut out);
+                out.into_boxed_slice()
+            }
+
+            /// Decodes a wide character string into a [`Box<[char]>`][Box],
+            /// returning the first wide character that could not be decoded as
+            /// an error.
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::{wcstr, WCStr};
+            ///
+            /// let chars = wcstr!(u32, "🦀rs").try_to_boxed_chars().unwrap();
+            /// assert_eq!(&*chars, ['🦀', 'r', 's']);
+            ///
+            /// // 🦀<invalid><nul>
+            /// let v: &[u16] = &[0xD83E, 0xDD80, 0xDC00, 0x0000];
+            /// let s = WCStr::from_slice_with_nul(v).unwrap();
+            ///
+            /// assert_eq!(s.try_to_boxed_chars().unwrap_err().code(), 0xDC00);
+            /// ```
+            pub fn try_to_boxed_chars(&self) -> Result<Box<[char]>, DecodeWideError<T>> {
+                let mut out = Vec::new();
+                self.decode_into(&mut out)?;
+                Ok(out.into_boxed_slice())
+            }
+
+            /// Decodes a wide character string, appending the decoded [`char`]s
+            /// onto the end of `out` and replacing invalid data with
+            /// [the replacement character (`U+FFFD`)][U+FFFD].
+            ///
+            /// This allows a buffer to be reused across calls, avoiding an
+            /// allocation for each decoded string.
+            ///
+            /// [U+FFFD]: char::REPLACEMENT_CHARACTER
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::{wcstr, WCStr};
+            ///
+            /// let mut out = Vec::new();
+            /// wcstr!(u16, "ab").decode_lossy_into(&mut out);
+            ///
+            /// // <invalid>c<nul>
+            /// let v: &[u16] = &[0xDD1E, 0x0063, 0x0000];
+            /// WCStr::from_slice_with_nul(v).unwrap().decode_lossy_into(&mut out);
+            ///
+            /// assert_eq!(out, ['a', 'b', '\u{FFFD}', 'c']);
+            /// ```
+            pub fn decode_lossy_into(&self, out: &mut Vec<char>) {
+                let chars = self.chars();
+                out.reserve(chars.size_hint().0);
+                out.extend(chars.map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER)));
+            }
+
+            /// Decodes a wide character string, appending the decoded [`char`]s
+            /// onto the end of `out` and returning the first wide character
+            /// that could not be decoded as an error.
+            ///
+            /// On error `out` is left unchanged.
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::{wcstr, WCStr};
+            ///
+            /// let mut out = Vec::new();
+            /// assert!(wcstr!(u32, "ab").decode_into(&mut out).is_ok());
+            ///
+            /// // c<invalid><nul>
+            /// let v: &[u16] = &[0x0063, 0xDD1E, 0x0000];
+            /// let err = WCStr::from_slice_with_nul(v).unwrap().decode_into(&mut out).unwrap_err();
+            ///
+            /// assert_eq!(err.code(), 0xDD1E);
+            /// assert_eq!(out, ['a', 'b']);
+            /// ```
+            pub fn decode_into(&self, out: &mut Vec<char>) -> Result<(), DecodeWideError<T>> {
+                let chars = self.chars();
+                out.reserve(chars.size_hint().0);
+
+                let len = out.len();
+                for r in chars {
+                    match r {
+                        Ok(c) => out.push(c),
+                        Err(e) => {
+                            out.truncate(len);
+                            return Err(e);
+                        }
+                    }
+                }
+                Ok(())
+            }
+
+            /// Decodes a wide character string into a UTF-8 [`CString`],
+            /// returning the first wide character that could not be decoded as
+            /// an error.
+            ///
+            /// A `WCStr` has no interior NUL characters, and decoding never
+            /// produces one, so the result is always a valid `CString`.
+            ///
+            /// [`CString`]: std::ffi::CString
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::{wcstr, WCStr};
+            ///
+            /// let c = wcstr!(u16, "🦀 rust").to_cstring().unwrap();
+            /// assert_eq!(c.to_str(), Ok("🦀 rust"));
+            ///
+            /// // a<invalid><nul>
+            /// let v: &[u16] = &[0x0061, 0xDD1E, 0x0000];
+            /// let s = WCStr::from_slice_with_nul(v).unwrap();
+            ///
+            /// assert_eq!(s.to_cstring().unwrap_err().code(), 0xDD1E);
+            /// ```
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn to_cstring(&self) -> Result<std::ffi::CString, DecodeWideError<T>> {
+                // SAFETY: Decoding a `WCStr` never produces a NUL character.
+                self.decode().map(|s| unsafe { std::ffi::CString::from_vec_unchecked(s.into_bytes()) })
+            }
+
+            /// Decodes a wide character string into a UTF-8 [`CString`],
+            /// replacing invalid data with
+            /// [the replacement character (`U+FFFD`)][U+FFFD].
+            ///
+            /// [`CString`]: std::ffi::CString
+            /// [U+FFFD]: char::REPLACEMENT_CHARACTER
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::WCStr;
+            ///
+            /// // a<invalid><nul>
+            /// let v: &[u16] = &[0x0061, 0xDD1E, 0x0000];
+            /// let s = WCStr::from_slice_with_nul(v).unwrap();
+            ///
+            /// assert_eq!(s.to_cstring_lossy().to_str(), Ok("a\u{FFFD}"));
+            /// ```
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn to_cstring_lossy(&self) -> std::ffi::CString {
+                // SAFETY: Decoding a `WCStr` never produces a NUL character,
+                //         and neither does the replacement character.
+                unsafe { std::ffi::CString::from_vec_unchecked(self.to_string_lossy().into_bytes()) }
+            }
+
+            /// Creates a new [`WCString`] by repeating a wide string `n` times.
+            ///
+            /// # Panics
+            ///
+            /// This function will panic if the capacity would overflow.
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::wcstr;
+            ///
+            /// let s = wcstr!(u16, "abc").repeat(3);
+            /// assert_eq!("abcabcabc", s.as_wcstr().to_string_lossy());
+            ///
+            /// let s = wcstr!(u32, "abc").repeat(0);
+            /// assert_eq!(s.as_wcstr().to_slice_with_nul(), &[0]);
+            /// ```
+            ///
+            /// A panic upon overflow:
+            ///
+            /// ```should_panic
+            /// use thicc::wcstr;
+            ///
+            /// // This will panic at runtime.
+            /// let huge = wcstr!("0123456789abcdef").repeat(usize::MAX);
+            /// ```
+            pub fn repeat(&self, n: usize) -> WCString<T> {
+                let slice = self.to_slice();
+                let capacity = slice
+                    .len()
+                    .checked_mul(n)
+                    .and_then(|len| len.checked_add(1))
+                    .expect("capacity overflow");
+
+                let mut buf = Vec::with_capacity(capacity);
+                for _ in 0..n {
+                    buf.extend_from_slice(slice);
+                }
+                buf.push(T::NUL);
+
+                // SAFETY: `self` contains no interior NUL characters, and a
+                //         single NUL-terminator was pushed at the end.
+                unsafe { WCString::from_vec_with_nul_unchecked(buf) }
+            }
+
+            /// Copies the wide characters of a wide string into a new [`Vec`],
+            /// without the trailing NUL-terminator.
+            ///
+            /// > **Note**: This operation is not zero-cost, requiring iteration
+            /// > through all bytes of the string to calculate the length.
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::wcstr;
+            ///
+            /// let v = wcstr!(u16, "hi").to_vec();
+            /// assert_eq!(v, [0x0068, 0x0069]);
+            /// ```
+            #[inline]
+            pub fn to_vec(&self) -> Vec<T> {
+                self.to_slice().to_vec()
+            }
+
+            /// Copies the wide characters of a wide string into a new [`Vec`],
+            /// including the trailing NUL-terminator.
+            ///
+            /// > **Note**: This operation is not zero-cost, requiring iteration
+            /// > through all bytes of the string to calculate the length.
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::wcstr;
+            ///
+            /// let v = wcstr!(u32, "hi").to_vec_with_nul();
+            /// assert_eq!(v, [0x0068, 0x0069, 0x0000]);
+            /// ```
+            #[inline]
+            pub fn to_vec_with_nul(&self) -> Vec<T> {
+                self.to_slice_with_nul().to_vec()
+            }
+
+            /// Copies the wide characters of a wide string into a new [`Vec`],
+            /// reversing the byte order of each, without the trailing
+            /// NUL-terminator.
+            ///
+            /// > **Note**: This operation is not zero-cost, requiring iteration
+            /// > through all bytes of the string to calculate the length.
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::wcstr;
+            ///
+            /// let v = wcstr!(u16, "hi").to_vec_swapped();
+            /// assert_eq!(v, [0x6800, 0x6900]);
+            ///
+            /// let v = wcstr!(u32, "🦀").to_vec_swapped();
+            /// assert_eq!(v, [0x80F9_0100]);
+            /// ```
+            #[inline]
+            pub fn to_vec_swapped(&self) -> Vec<T> {
+                self.to_slice().iter().map(|u| u.swap_bytes()).collect()
+            }
+
+            /// Replaces every occurrence of the wide character `from` with
+            /// `to`, only allocating if `from` is found.
+            ///
+            /// This is useful for normalizing path separators, such as
+            /// replacing `/` with `\\` on Windows.
+            ///
+            /// > **Note**: This operation is not zero-cost, requiring iteration
+            /// > through all bytes of the string to calculate the length.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `to` is the NUL character.
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use std::borrow::Cow;
+            /// use thicc::wcstr;
+            ///
+            /// let s = wcstr!(u16, "C:/Users/ferris");
+            /// let normalized = s.normalize_separators(b'/' as u16, b'\\' as u16);
+            ///
+            /// assert!(matches!(normalized, Cow::Owned(_)));
+            /// assert_eq!(normalized.to_string_lossy(), "C:\\Users\\ferris");
+            ///
+            /// let s = wcstr!(u16, "C:\\Users\\ferris");
+            /// let normalized = s.normalize_separators(b'/' as u16, b'\\' as u16);
+            ///
+            /// assert!(matches!(normalized, Cow::Borrowed(_)));
+            /// ```
+            pub fn normalize_separators(&self, from: T, to: T) -> Cow<'_, WCStr<T>> {
+                use crate::char::SpecFind;
+
+                assert!(to != T::NUL, "cannot replace with a NUL character");
+
+                let slice = self.to_slice();
+                let first = match SpecFind::wmemchr(from, slice) {
+                    Some(first) => first,
+                    None => return Cow::Borrowed(self),
+                };
+
+                let mut buf = self.to_vec_with_nul();
+                for u in &mut buf[first..slice.len()] {
+                    if *u == from {
+                        *u = to;
+                    }
+                }
+
+                // SAFETY: `to` is not NUL, so the buffer still has a single
+                //         NUL-terminator at the end.
+                Cow::Owned(unsafe { WCString::from_vec_with_nul_unchecked(buf) })
+            }
+
+            /// Re-encodes a wide string into a [`WCString`] with a different
+            /// backing type, returning the first wide character that could not
+            /// be decoded as an error.
+            ///
+            /// This transcodes directly between UTF-16 and UTF-32, without
+            /// going through a [`String`].
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::{wcstr, WCStr, WCString};
+            ///
+            /// let s: WCString<u32> = wcstr!(u16, "🦀rs").transcode().unwrap();
+            /// assert_eq!(s.as_wcstr().to_slice(), &[0x1F980, 0x0072, 0x0073]);
+            ///
+            /// // a<invalid><nul>
+            /// let v: &[u16] = &[0x0061, 0xDC00, 0x0000];
+            /// let s = WCStr::from_slice_with_nul(v).unwrap();
+            ///
+            /// assert_eq!(s.transcode::<u32>().err().unwrap().code(), 0xDC00);
+            /// ```
+            pub fn transcode<U: Wide>(&self) -> Result<WCString<U>, DecodeWideError<T>> {
+                let chars = self.chars();
+
+                let mut v = Vec::with_capacity(chars.size_hint().0 + 1);
+                let mut buf = [U::NUL; 2];
+                for c in chars {
+                    v.extend_from_slice(U::encode(c?, &mut buf));
+                }
+                v.push(U::NUL);
+
+                // SAFETY: Decoding a `WCStr` never produces a NUL character, and
+                //         a single NUL-terminator was pushed at the end.
+                Ok(unsafe { WCString::from_vec_with_nul_unchecked(v) })
+            }
+
+            /// Re-encodes a wide string into a [`WCString`] with a different
+            /// backing type, replacing invalid data with
+            /// [the replacement character (`U+FFFD`)][U+FFFD].
+            ///
+            /// [U+FFFD]: char::REPLACEMENT_CHARACTER
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::{WCStr, WCString};
+            ///
+            /// // a<invalid><nul>
+            /// let v: &[u32] = &[0x0061, 0xD800, 0x0000];
+            /// let s = WCStr::from_slice_with_nul(v).unwrap();
+            ///
+            /// let s: WCString<u16> = s.transcode_lossy();
+            /// assert_eq!(s.as_wcstr().to_slice(), &[0x0061, 0xFFFD]);
+            /// ```
+            pub fn transcode_lossy<U: Wide>(&self) -> WCString<U> {
+                let chars = self.chars();
+
+                let mut v = Vec::with_capacity(chars.size_hint().0 + 1);
+                let mut buf = [U::NUL; 2];
+                for c in chars {
+                    let c = c.unwrap_or(char::REPLACEMENT_CHARACTER);
+                    v.extend_from_slice(U::encode(c, &mut buf));
+                }
+                v.push(U::NUL);
+
+                // SAFETY: Decoding a `WCStr` never produces a NUL character, and
+                //         a single NUL-terminator was pushed at the end.
+                unsafe { WCString::from_vec_with_nul_unchecked(v) }
+            }
+
+            /// Creates a new [`WCString`] by applying `f` to each wide character
+            /// of a wide string.
+            ///
+            /// This generalizes simple transformations such as replacing one
+            /// wide character with another, case folding and swapping the byte
+            /// order.
+            ///
+            /// > **Note**: This operation is not zero-cost, requiring iteration
+            /// > through all bytes of the string to calculate the length.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `f` returns the NUL character, since it would become an
+            /// interior NUL of the new wide string.
+            ///
+            /// # Examples
+            ///
+            /// Basic usage:
+            ///
+            /// ```
+            /// use thicc::wcstr;
+            ///
+            /// let s = wcstr!(u16, "a-b-c").map_units(|u| if u == b'-' as u16 { b'_' as u16 } else { u });
+            ///
+            /// assert_eq!(s.as_wcstr().to_string_lossy(), "a_b_c");
+            /// ```
+            ///
+            /// Producing a NUL character panics:
+            ///
+            /// ```should_panic
+            /// use thicc::wcstr;
+            ///
+            /// wcstr!(u16, "abc").map_units(|u| u - b'a' as u16);
+            /// ```
+            pub fn map_units<F: FnMut(T) -> T>(&self, mut f: F) -> WCString<T> {
+                let slice = self.to_slice();
+
+                let mut v = Vec::with_capacity(slice.len() + 1);
+                for &u in slice {
+                    let u = f(u);
+                    assert!(u != T::NUL, "cannot map to a NUL character");
+                    v.push(u);
+                }
+                v.push(T::NUL);
+
+                // SAFETY: No NUL characters were produced, and a single
+                //         NUL-terminator was pushed at the end.
+                unsafe { WCString::from_vec_with_nul_unchecked(v) }
+            }
+        }
+
+        /// Decodes a wide character string into a [`String`] using
+        /// [`WCStr::to_string_lossy`].
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// use thicc::wcstr;
+        ///
+        /// let s: String = wcstr!("hi").into();
+        /// assert_eq!(s, "hi");
+        /// ```
+        impl<T: Wide> From<&WCStr<T>> for String {
+            #[inline]
+            fn from(s: &WCStr<T>) -> String {
+                s.to_string_lossy()
+            }
+        }
+
+        /// Decodes a wide character string into an owned [`Cow<str>`] using
+        /// [`WCStr::to_string_lossy`].
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// use std::borrow::Cow;
+        /// use thicc::wcstr;
+        ///
+        /// let s: Cow<str> = wcstr!(u16, "hi").into();
+        /// assert_eq!(s, "hi");
+        /// ```
+        impl<'a, T: Wide> From<&'a WCStr<T>> for Cow<'a, str> {
+            #[inline]
+            fn from(s: &'a WCStr<T>) -> Cow<'a, str> {
+                Cow::Owned(s.to_string_lossy())
+            }
+        }
+
+        /// Copies a borrowed wide string into a [`WCString`].
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// use thicc::{wcstr, WCString};
+        ///
+        /// let s: WCString<u16> = wcstr!(u16, "hi").to_owned();
+        /// assert!(s.as_wcstr() == wcstr!(u16, "hi"));
+        /// ```
+        impl<T: Wide> ToOwned for WCStr<T> {
+            type Owned = WCString<T>;
+
+            #[inline]
+            fn to_owned(&self) -> WCString<T> {
+                // SAFETY: `self` is a valid `WCStr`, so the copy is too.
+                unsafe { WCString::from_vec_with_nul_unchecked(self.to_vec_with_nul()) }
+            }
+        }
+    }
+}
+
+/// An error indicating that a NUL byte was not in the expected position.
+///
+/// The slice used to create a [`WCStr`] must have one and only one NUL
+/// character, positioned at the end.
+///
+/// This error is created by the [`WCStr::from_slice_with_nul`] and
+/// [`WCStr::from_slice_up_to_nul`] methods. See their documentation for more.
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct FromSliceWithNulError {
     kind: FromSliceWithNulErrorKind,
@@ -411,3 +3049,83 @@ impl FromSliceWithNulError {
         }
     }
 }
+
+/// An error indicating that no NUL-terminator was found within a bounded scan.
+///
+/// This error is created by the [`WCStr::from_ptr_with_max`] method.
+/// See its documentation for more.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct NoNulError {
+    max_units: usize,
+}
+
+impl NoNulError {
+    const fn new(max_units: usize) -> NoNulError {
+        NoNulError { max_units }
+    }
+}
+
+/// An error indicating that a wide string is too long to be represented as a
+/// slice.
+///
+/// This error is created by the [`WCStr::try_len`] method.
+/// See its documentation for more.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct LenOverflowError {
+    max_len: usize,
+}
+
+impl LenOverflowError {
+    const fn new(max_len: usize) -> LenOverflowError {
+        LenOverflowError { max_len }
+    }
+}
+
+/// An error indicating that a wide string could not be encoded as UTF-8 into a
+/// buffer.
+///
+/// This error is created by the [`WCStr::encode_utf8_into`] method.
+/// See its documentation for more.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct EncodeUtf8Error<T: Wide = WChar> {
+    kind: EncodeUtf8ErrorKind<T>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum EncodeUtf8ErrorKind<T: Wide> {
+    BufferTooSmall(usize),
+    Decode(DecodeWideError<T>),
+}
+
+impl<T: Wide> EncodeUtf8Error<T> {
+    const fn buffer_too_small(required: usize) -> EncodeUtf8Error<T> {
+        EncodeUtf8Error {
+            kind: EncodeUtf8ErrorKind::BufferTooSmall(required),
+        }
+    }
+
+    const fn decode(error: DecodeWideError<T>) -> EncodeUtf8Error<T> {
+        EncodeUtf8Error {
+            kind: EncodeUtf8ErrorKind::Decode(error),
+        }
+    }
+
+    /// Returns the number of bytes required to encode the whole string, if
+    /// the buffer was too small.
+    #[inline]
+    pub fn required_len(&self) -> Option<usize> {
+        match self.kind {
+            EncodeUtf8ErrorKind::BufferTooSmall(required) => Some(required),
+            EncodeUtf8ErrorKind::Decode(_) => None,
+        }
+    }
+
+    /// Returns the error that occurred while decoding the wide string, if any.
+    #[inline]
+    pub fn decode_error(&self) -> Option<&DecodeWideError<T>> {
+        match &self.kind {
+            EncodeUtf8ErrorKind::BufferTooSmall(_) => None,
+            EncodeUtf8ErrorKind::Decode(error) => Some(error),
+        }
+    }
+}
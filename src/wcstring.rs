@@ -0,0 +1,1540 @@
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::FromIterator;
+use core::mem;
+use core::ops::Deref;
+use core::str::FromStr;
+
+use crate::alloc::{Box, String, Vec};
+use crate::char::{DecodeWideError, WChar, Wide};
+use crate::wcstr::{FromSliceWithNulError, WCStr, hash_wide_str};
+
+/// An owned, C-style wide character string.
+///
+/// The backing buffer always ends with a single NUL-terminator and contains no
+/// interior NUL characters.
+#[derive(Clone)]
+pub struct WCString<T: Wide = WChar> {
+    inner: Vec<T>,
+}
+
+assert_impls!(WCString: Send & Sync);
+assert_impls!(WCString<u16>: Send & Sync);
+assert_impls!(WCString<u32>: Send & Sync);
+assert_impls!(WCString<i16>: Send & Sync);
+assert_impls!(WCString<i32>: Send & Sync);
+
+impl<T: Wide> WCString<T> {
+    /// Creates a new empty `WCString`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// let s = WCString::<u16>::new();
+    ///
+    /// assert_eq!(s.as_wcstr().len(), 0);
+    /// ```
+    #[inline]
+    pub fn new() -> WCString<T> {
+        // SAFETY: A lone NUL-terminator is a valid empty string.
+        unsafe { WCString::from_vec_with_nul_unchecked(Vec::from([T::NUL])) }
+    }
+
+    /// Creates a new empty `WCString` with enough capacity for `chars`
+    /// [`char`]s of at most the given width, and the NUL-terminator.
+    ///
+    /// Pushing up to `chars` [`char`]s no wider than `widest` onto the string
+    /// will not reallocate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the capacity overflows.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::{CharWidth, WCString};
+    ///
+    /// let mut s = WCString::<u16>::with_exact_capacity_for(3, CharWidth::Supplementary);
+    /// let capacity = s.capacity();
+    /// assert!(capacity >= 7);
+    ///
+    /// for c in "🦀🦀🦀".chars() {
+    ///     s.push_char(c);
+    /// }
+    ///
+    /// // No reallocation took place.
+    /// assert_eq!(s.capacity(), capacity);
+    /// assert_eq!(s.as_wcstr().len(), 6);
+    /// ```
+    pub fn with_exact_capacity_for(chars: usize, widest: CharWidth) -> WCString<T> {
+        let units = match widest {
+            CharWidth::Bmp => 1,
+            CharWidth::Supplementary => T::encode('\u{10000}', &mut [T::NUL; 2]).len(),
+        };
+        let capacity = chars
+            .checked_mul(units)
+            .and_then(|n| n.checked_add(1))
+            .expect("capacity overflow");
+
+        let mut v = Vec::with_capacity(capacity);
+        v.push(T::NUL);
+
+        // SAFETY: A lone NUL-terminator is a valid empty string.
+        unsafe { WCString::from_vec_with_nul_unchecked(v) }
+    }
+
+    /// Returns the number of wide characters this `WCString` can hold without
+    /// reallocating, including the NUL-terminator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::{CharWidth, WCString};
+    ///
+    /// let s = WCString::<u32>::with_exact_capacity_for(10, CharWidth::Supplementary);
+    ///
+    /// assert!(s.capacity() >= 11);
+    /// ```
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.inner.capacity()
+    }
+
+    /// Creates a `WCString` from the little-endian byte representation of a
+    /// wide string, without a NUL-terminator.
+    ///
+    /// Each wide character is read from `size_of::<T>()` bytes, independent of
+    /// the endianness of the host.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length of `bytes` is not a multiple of
+    /// `size_of::<T>()`, or if any wide character read is NUL.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// let s = WCString::<u16>::from_bytes_le(&[0x3E, 0xD8, 0x80, 0xDD, 0x21, 0x00]).unwrap();
+    /// assert_eq!(s.as_wcstr().to_string_lossy(), "🦀!");
+    ///
+    /// let s = WCString::<u32>::from_bytes_le(&[0x80, 0xF9, 0x01, 0x00]).unwrap();
+    /// assert_eq!(s.as_wcstr().to_string_lossy(), "🦀");
+    ///
+    /// // Odd number of bytes for a 16-bit wide string.
+    /// assert!(WCString::<u16>::from_bytes_le(&[0x21, 0x00, 0x21]).is_err());
+    /// // Interior NUL character.
+    /// assert!(WCString::<u32>::from_bytes_le(&[0x00, 0x00, 0x00, 0x00]).is_err());
+    /// ```
+    #[inline]
+    pub fn from_bytes_le(bytes: &[u8]) -> Result<WCString<T>, FromBytesError> {
+        WCString::from_bytes_with(bytes, T::from_le_bytes)
+    }
+
+    /// Creates a `WCString` from the big-endian byte representation of a wide
+    /// string, without a NUL-terminator.
+    ///
+    /// Each wide character is read from `size_of::<T>()` bytes, independent of
+    /// the endianness of the host.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the length of `bytes` is not a multiple of
+    /// `size_of::<T>()`, or if any wide character read is NUL.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// let s = WCString::<u16>::from_bytes_be(&[0xD8, 0x3E, 0xDD, 0x80, 0x00, 0x21]).unwrap();
+    /// assert_eq!(s.as_wcstr().to_string_lossy(), "🦀!");
+    ///
+    /// let s = WCString::<u32>::from_bytes_be(&[0x00, 0x01, 0xF9, 0x80]).unwrap();
+    /// assert_eq!(s.as_wcstr().to_string_lossy(), "🦀");
+    ///
+    /// // Length not a multiple of 4 for a 32-bit wide string.
+    /// assert!(WCString::<u32>::from_bytes_be(&[0x00, 0x00, 0x00, 0x21, 0x00]).is_err());
+    /// ```
+    #[inline]
+    pub fn from_bytes_be(bytes: &[u8]) -> Result<WCString<T>, FromBytesError> {
+        WCString::from_bytes_with(bytes, T::from_be_bytes)
+    }
+
+    fn from_bytes_with(bytes: &[u8], f: fn(&[u8]) -> T) -> Result<WCString<T>, FromBytesError> {
+        let chunks = bytes.chunks_exact(mem::size_of::<T>());
+        if !chunks.remainder().is_empty() {
+            return Err(FromBytesError::invalid_length(bytes.len()));
+        }
+
+        let mut v = Vec::with_capacity(chunks.len() + 1);
+        for (i, chunk) in chunks.enumerate() {
+            let u = f(chunk);
+            if u == T::NUL {
+                return Err(FromBytesError::interior_nul(i));
+            }
+            v.push(u);
+        }
+        v.push(T::NUL);
+
+        // SAFETY: No NUL characters were read, and a single NUL-terminator was
+        //         pushed at the end.
+        Ok(unsafe { WCString::from_vec_with_nul_unchecked(v) })
+    }
+
+    /// Creates a `WCString` from an iterator of wide characters, without a
+    /// NUL-terminator.
+    ///
+    /// The wide characters are checked for NUL characters as they are
+    /// collected, and a NUL-terminator is appended, so no second pass over
+    /// the string is needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `iter` yields a NUL character, reporting its
+    /// position.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// let s = WCString::try_from_units("hi".encode_utf16()).unwrap();
+    /// assert_eq!(s.as_wcstr().to_slice_with_nul(), &[0x68, 0x69, 0x00]);
+    ///
+    /// let err = WCString::try_from_units(vec![0x61u32, 0x62, 0x00, 0x63]).err().unwrap();
+    /// assert_eq!(err.nul_position(), 2);
+    /// ```
+    pub fn try_from_units<I: IntoIterator<Item = T>>(
+        iter: I,
+    ) -> Result<WCString<T>, InteriorNulError> {
+        let iter = iter.into_iter();
+
+        let mut v = Vec::with_capacity(iter.size_hint().0 + 1);
+        for (i, u) in iter.enumerate() {
+            if u == T::NUL {
+                return Err(InteriorNulError::new(i));
+            }
+            v.push(u);
+        }
+        v.push(T::NUL);
+
+        // SAFETY: No NUL characters were collected, and a single
+        //         NUL-terminator was pushed at the end.
+        Ok(unsafe { WCString::from_vec_with_nul_unchecked(v) })
+    }
+
+    /// Creates a `WCString` from an iterator of decoded [`char`]s, replacing
+    /// each error with [the replacement character (`U+FFFD`)][U+FFFD].
+    ///
+    /// This pairs with [`WCStr::chars`] to transcode between backing types.
+    ///
+    /// [U+FFFD]: char::REPLACEMENT_CHARACTER
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator yields the NUL character.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::{WCStr, WCString};
+    ///
+    /// // a<invalid><nul>
+    /// let v: &[u16] = &[0x0061, 0xDC00, 0x0000];
+    /// let s = WCStr::from_slice_with_nul(v).unwrap();
+    ///
+    /// let s = WCString::<u32>::from_chars_lossy(s.chars());
+    /// assert_eq!(s.as_wcstr().to_slice(), &[0x0061, 0xFFFD]);
+    /// ```
+    pub fn from_chars_lossy<E, I: IntoIterator<Item = Result<char, E>>>(iter: I) -> WCString<T> {
+        iter.into_iter()
+            .map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect()
+    }
+
+    /// Creates a `WCString` from an iterator of decoded [`char`]s, returning
+    /// the first error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator yields the NUL character.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::{wcstr, WCStr, WCString};
+    ///
+    /// let s = WCString::<u32>::try_from_chars(wcstr!(u16, "🦀").chars()).unwrap();
+    /// assert_eq!(s.as_wcstr().to_slice(), &[0x1F980]);
+    ///
+    /// // a<invalid><nul>
+    /// let v: &[u16] = &[0x0061, 0xDC00, 0x0000];
+    /// let s = WCStr::from_slice_with_nul(v).unwrap();
+    ///
+    /// let err = WCString::<u32>::try_from_chars(s.chars()).err().unwrap();
+    /// assert_eq!(err.code(), 0xDC00);
+    /// ```
+    pub fn try_from_chars<E, I: IntoIterator<Item = Result<char, E>>>(
+        iter: I,
+    ) -> Result<WCString<T>, E> {
+        let iter = iter.into_iter();
+
+        let mut string = WCString::new();
+        string.inner.reserve(iter.size_hint().0);
+        for r in iter {
+            string.push_char(r?);
+        }
+        Ok(string)
+    }
+
+    /// Creates a `WCString` by concatenating wide strings.
+    ///
+    /// The total length is calculated first, so the new string is allocated
+    /// exactly once.
+    ///
+    /// > **Note**: This operation is not zero-cost, requiring iteration through
+    /// > all bytes of each part twice, to calculate the length and to copy it.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::{wcstr, WCString};
+    ///
+    /// let s = WCString::concat(&[wcstr!(u32, "foo"), wcstr!(u32, "/"), wcstr!(u32, "bar")]);
+    /// assert_eq!(s.as_wcstr().to_string_lossy(), "foo/bar");
+    ///
+    /// assert!(WCString::<u32>::concat(&[]).as_wcstr().is_empty());
+    /// ```
+    pub fn concat(parts: &[&WCStr<T>]) -> WCString<T> {
+        let len = parts.iter().map(|s| s.len()).sum::<usize>();
+
+        let mut v = Vec::with_capacity(len + 1);
+        for s in parts {
+            v.extend_from_slice(s.to_slice());
+        }
+        v.push(T::NUL);
+
+        // SAFETY: None of the parts contain a NUL character, and a single
+        //         NUL-terminator was pushed at the end.
+        unsafe { WCString::from_vec_with_nul_unchecked(v) }
+    }
+
+    /// Creates a `WCString` by widening a UTF-8 [`CStr`] into the backing type
+    /// of the wide string.
+    ///
+    /// A `CStr` has no interior NUL characters, so this can only fail if the
+    /// `CStr` is not valid UTF-8. To widen a [`str`] use the [`FromStr`]
+    /// implementation instead.
+    ///
+    /// [`CStr`]: std::ffi::CStr
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`Utf8Error`] if the `CStr` is not valid UTF-8.
+    /// [`Utf8Error::valid_up_to`] gives the position of the offending byte.
+    ///
+    /// [`Utf8Error`]: core::str::Utf8Error
+    /// [`Utf8Error::valid_up_to`]: core::str::Utf8Error::valid_up_to
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::ffi::CStr;
+    /// use thicc::WCString;
+    ///
+    /// let c = CStr::from_bytes_with_nul("🦀\0".as_bytes()).unwrap();
+    /// let s = WCString::<u16>::from_cstr(c).unwrap();
+    ///
+    /// assert_eq!(s.as_wcstr().to_slice_with_nul(), &[0xD83E, 0xDD80, 0x0000]);
+    ///
+    /// let c = CStr::from_bytes_with_nul(b"ab\xFF\0").unwrap();
+    /// let err = WCString::<u16>::from_cstr(c).err().unwrap();
+    ///
+    /// assert_eq!(err.valid_up_to(), 2);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_cstr(s: &std::ffi::CStr) -> Result<WCString<T>, core::str::Utf8Error> {
+        let s = s.to_str()?;
+
+        // A UTF-8 string is never shorter than its UTF-16 or UTF-32 encoding.
+        let mut v = Vec::with_capacity(s.len() + 1);
+        let mut buf = [T::NUL; 2];
+        for c in s.chars() {
+            v.extend_from_slice(T::encode(c, &mut buf));
+        }
+        v.push(T::NUL);
+
+        // SAFETY: A `CStr` has no interior NUL characters, and a single
+        //         NUL-terminator was pushed at the end.
+        Ok(unsafe { WCString::from_vec_with_nul_unchecked(v) })
+    }
+
+    /// Creates a `WCString` from a vector of wide characters with a
+    /// NUL-terminator, taking ownership of the vector without copying.
+    ///
+    /// This is the owned equivalent of [`WCStr::from_slice_with_nul`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `v` is not NUL-terminated or contains an interior
+    /// NUL character. The original vector can be recovered from the error.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// // 🦀<nul>
+    /// let s = WCString::from_vec_with_nul(vec![0xD83Eu16, 0xDD80, 0x0000]).unwrap();
+    ///
+    /// assert_eq!(s.as_wcstr().to_string_lossy(), "🦀");
+    /// ```
+    ///
+    /// The vector is returned on failure:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// // a<nul>b<nul>
+    /// let err = WCString::from_vec_with_nul(vec![0x61u32, 0x00, 0x62, 0x00]).err().unwrap();
+    ///
+    /// assert_eq!(err.into_vec(), vec![0x61, 0x00, 0x62, 0x00]);
+    /// ```
+    pub fn from_vec_with_nul(v: Vec<T>) -> Result<WCString<T>, FromVecWithNulError<T>> {
+        match WCStr::from_slice_with_nul(&v) {
+            // SAFETY: The vector was just checked to be a valid `WCStr`.
+            Ok(_) => Ok(unsafe { WCString::from_vec_with_nul_unchecked(v) }),
+            Err(error) => Err(FromVecWithNulError { error, vec: v }),
+        }
+    }
+
+    /// Creates a `WCString` from a vector of wide characters with a
+    /// NUL-terminator, without checking the contents.
+    ///
+    /// # Safety
+    ///
+    /// `v` must be NUL-terminated and cannot contain any interior NUL
+    /// characters.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// // Hi<nul>
+    /// let s = unsafe { WCString::from_vec_with_nul_unchecked(vec![0x48u16, 0x69, 0x00]) };
+    ///
+    /// assert_eq!(s.as_wcstr().to_string_lossy(), "Hi");
+    /// ```
+    #[inline]
+    pub unsafe fn from_vec_with_nul_unchecked(v: Vec<T>) -> WCString<T> {
+        WCString { inner: v }
+    }
+
+    /// Returns a borrowed view of this wide string.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::{WCStr, WCString};
+    ///
+    /// let s = WCString::<u32>::new();
+    /// let w: &WCStr<u32> = s.as_wcstr();
+    ///
+    /// assert_eq!(w.to_slice_with_nul(), &[0]);
+    /// ```
+    #[inline]
+    pub fn as_wcstr(&self) -> &WCStr<T> {
+        // SAFETY: The backing buffer is always NUL-terminated and lives for as
+        //         long as the borrow of `self`.
+        unsafe { WCStr::from_slice_with_nul_unchecked(&self.inner) }
+    }
+
+    /// Returns a raw pointer to the buffer of this `WCString`, for passing to C
+    /// functions that modify a wide string in place.
+    ///
+    /// The pointer is valid for writes of the wide characters of the string,
+    /// up to and including the NUL-terminator, for as long as `self` is
+    /// mutably borrowed.
+    ///
+    /// # Invariants
+    ///
+    /// Obtaining the pointer is safe, but any writes through it must preserve
+    /// the invariants of a `WCString`:
+    ///
+    /// - The `WCString` stores the length of its buffer, and that length
+    ///   cannot be changed through the pointer. The NUL-terminator must stay
+    ///   exactly at position [`len()`], so C code must neither shorten the
+    ///   string by writing an earlier NUL character nor lengthen it by
+    ///   writing past the NUL-terminator.
+    ///
+    /// - No interior NUL characters may be written.
+    ///
+    /// [`len()`]: WCStr::len
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// /// Reverses a NUL-terminated string in place, like `_wcsrev`.
+    /// unsafe fn wcsrev(s: *mut u32) {
+    ///     let len = (0..).take_while(|&i| *s.add(i) != 0).count();
+    ///     std::slice::from_raw_parts_mut(s, len).reverse();
+    /// }
+    ///
+    /// let mut s: WCString<u32> = "rust".parse().unwrap();
+    /// unsafe { wcsrev(s.as_mut_ptr()) };
+    ///
+    /// assert_eq!(s.to_string_lossy(), "tsur");
+    /// ```
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.inner.as_mut_ptr()
+    }
+
+    /// Consumes the `WCString` and transfers ownership of the wide string to
+    /// the caller, such as across an FFI boundary.
+    ///
+    /// The returned pointer points to the first wide character, and the
+    /// string keeps its NUL-terminator. The buffer is first shrunk so that
+    /// its capacity is exactly the length of the string plus one, which is
+    /// what allows [`WCString::from_raw`] to recover it from the pointer
+    /// alone.
+    ///
+    /// The pointer must be passed back to [`WCString::from_raw`] to be freed.
+    /// Memory allocated by Rust must not be freed by C's `free`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// let s: WCString<u16> = "rust".parse().unwrap();
+    /// let ptr = s.into_raw();
+    ///
+    /// unsafe {
+    ///     assert_eq!(*ptr, 0x0072);
+    ///     assert_eq!(*ptr.add(4), 0x0000);
+    ///
+    ///     // Retake the pointer to free the memory.
+    ///     let s = WCString::from_raw(ptr);
+    ///     assert_eq!(s.to_string_lossy(), "rust");
+    /// }
+    /// ```
+    #[inline]
+    pub fn into_raw(self) -> *mut T {
+        Box::into_raw(self.inner.into_boxed_slice()) as *mut T
+    }
+
+    /// Retakes ownership of a wide string that was transferred by
+    /// [`WCString::into_raw`].
+    ///
+    /// The length of the string is recovered by finding the NUL-terminator,
+    /// and the capacity of the buffer is taken to be that length plus one, as
+    /// left by [`WCString::into_raw`].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have been returned by [`WCString::into_raw`] for the same
+    /// backing type `T`, and must not be used again afterwards. The wide
+    /// string may have been modified in place, but its NUL-terminator must
+    /// not have moved: writing an earlier NUL character makes the recovered
+    /// capacity wrong, and freeing the buffer is then undefined behavior.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// let s: WCString<u32> = "ok".parse().unwrap();
+    /// let s = unsafe { WCString::from_raw(s.into_raw()) };
+    ///
+    /// assert_eq!(s.as_wcstr().to_slice_with_nul(), &[0x006F, 0x006B, 0x0000]);
+    /// ```
+    pub unsafe fn from_raw(ptr: *mut T) -> WCString<T> {
+        use crate::char::SpecLen;
+
+        let len = SpecLen::wcslen(ptr) + 1;
+        WCString::from_vec_with_nul_unchecked(Vec::from_raw_parts(ptr, len, len))
+    }
+
+    /// Consumes the `WCString` and returns the underlying buffer, including
+    /// the trailing NUL character.
+    #[cfg(feature = "widestring-interop")]
+    #[inline]
+    pub(crate) fn into_vec_with_nul(self) -> Vec<T> {
+        self.inner
+    }
+
+    /// Appends a given string slice onto the end of this `WCString`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `s` contains a NUL character. See [`WCString::try_push_str`]
+    /// for a non-panicking alternative.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// let mut s = WCString::<u16>::new();
+    ///
+    /// s.push_str("foo");
+    /// s.push_str("🦀");
+    ///
+    /// assert_eq!(s.as_wcstr().to_slice(), &[0x0066, 0x006F, 0x006F, 0xD83E, 0xDD80]);
+    /// ```
+    #[inline]
+    pub fn push_str(&mut self, s: &str) {
+        if let Err(e) = self.try_push_str(s) {
+            panic!(
+                "string contains an interior NUL character at byte {}",
+                e.nul_position()
+            );
+        }
+    }
+
+    /// Appends the given [`char`] to the end of this `WCString`.
+    ///
+    /// For UTF-16 backings a [`char`] outside of the Basic Multilingual Plane
+    /// is encoded as a surrogate pair.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `c` is the NUL character.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// let mut s = WCString::<u16>::new();
+    ///
+    /// s.push_char('a');
+    /// s.push_char('🦀');
+    ///
+    /// assert_eq!(s.as_wcstr().to_slice(), &[0x0061, 0xD83E, 0xDD80]);
+    /// ```
+    ///
+    /// Supplementary [`char`]s round-trip through a surrogate pair:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// for c in ('\u{10000}'..='\u{10FFFF}').step_by(7) {
+    ///     let mut s = WCString::<u16>::new();
+    ///     s.push_char(c);
+    ///
+    ///     let units = s.as_wcstr().to_slice();
+    ///     assert_eq!(units.len(), 2);
+    ///     assert!((0xD800..0xDC00).contains(&units[0]));
+    ///     assert!((0xDC00..0xE000).contains(&units[1]));
+    ///
+    ///     let mut chars = s.as_wcstr().chars();
+    ///     assert_eq!(chars.next(), Some(Ok(c)));
+    ///     assert_eq!(chars.next(), None);
+    /// }
+    /// ```
+    pub fn push_char(&mut self, c: char) {
+        assert!(c != '\0', "cannot push a NUL character onto a `WCString`");
+
+        let mut buf = [T::NUL; 2];
+        let units = T::encode(c, &mut buf);
+
+        self.inner.pop();
+        self.inner.extend_from_slice(units);
+        self.inner.push(T::NUL);
+    }
+
+    /// Inserts a [`char`] into this `WCString` at a wide character position.
+    ///
+    /// For UTF-16 backings a [`char`] outside of the Basic Multilingual Plane
+    /// is inserted as a surrogate pair.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than the length of the string, if `idx` lies
+    /// between the two halves of a surrogate pair, or if `c` is the NUL
+    /// character.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// let mut s: WCString<u16> = "ac".parse().unwrap();
+    ///
+    /// s.insert_char(1, 'b');
+    /// s.insert_char(3, '🦀');
+    ///
+    /// assert_eq!(s.as_wcstr().to_string_lossy(), "abc🦀");
+    /// ```
+    ///
+    /// Splitting a surrogate pair panics:
+    ///
+    /// ```should_panic
+    /// use thicc::WCString;
+    ///
+    /// let mut s: WCString<u16> = "🦀".parse().unwrap();
+    ///
+    /// // This will panic at runtime.
+    /// s.insert_char(1, 'a');
+    /// ```
+    pub fn insert_char(&mut self, idx: usize, c: char) {
+        let len = self.inner.len() - 1;
+        assert!(
+            idx <= len,
+            "insertion index (is {}) should be <= len (is {})",
+            idx,
+            len
+        );
+        assert!(
+            T::is_char_boundary(&self.inner[..len], idx),
+            "insertion index (is {}) splits a surrogate pair",
+            idx
+        );
+        assert!(c != '\0', "cannot insert a NUL character into a `WCString`");
+
+        let mut buf = [T::NUL; 2];
+        let units = T::encode(c, &mut buf);
+
+        self.inner.splice(idx..idx, units.iter().copied());
+    }
+
+    /// Removes the [`char`] starting at a wide character position from this
+    /// `WCString` and returns it.
+    ///
+    /// A surrogate pair is removed as a whole. Invalid data is removed one
+    /// wide character at a time, and returned as an error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` is larger than or equal to the length of the string, or
+    /// if `idx` lies between the two halves of a surrogate pair.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// let mut s: WCString<u16> = "a🦀b".parse().unwrap();
+    ///
+    /// assert_eq!(s.remove_char(1), Ok('🦀'));
+    /// assert_eq!(s.remove_char(0), Ok('a'));
+    ///
+    /// assert_eq!(s.as_wcstr().to_string_lossy(), "b");
+    /// ```
+    ///
+    /// Splitting a surrogate pair panics:
+    ///
+    /// ```should_panic
+    /// use thicc::WCString;
+    ///
+    /// let mut s: WCString<u16> = "🦀".parse().unwrap();
+    ///
+    /// // This will panic at runtime.
+    /// s.remove_char(1);
+    /// ```
+    pub fn remove_char(&mut self, idx: usize) -> Result<char, DecodeWideError<T>> {
+        let len = self.inner.len() - 1;
+        assert!(
+            idx < len,
+            "removal index (is {}) should be < len (is {})",
+            idx,
+            len
+        );
+        assert!(
+            T::is_char_boundary(&self.inner[..len], idx),
+            "removal index (is {}) splits a surrogate pair",
+            idx
+        );
+
+        // SAFETY: `idx` is in bounds, so the tail of the buffer starting at
+        //         `idx` is still NUL-terminated.
+        let tail = unsafe { WCStr::from_ptr(self.inner.as_ptr().add(idx)) };
+        // The tail is not empty, so decoding yields at least one item.
+        let decoded = tail.chars().next().unwrap();
+
+        let n = match decoded {
+            Ok(c) => T::encode(c, &mut [T::NUL; 2]).len(),
+            Err(_) => 1,
+        };
+        self.inner.drain(idx..idx + n);
+
+        decoded
+    }
+
+    /// Retains only the [`char`]s specified by the predicate.
+    ///
+    /// In other words, removes all [`char`]s `c` such that `f(c)` returns
+    /// `false`. This method operates in place, visiting each [`char`] exactly
+    /// once in the original order, and preserves the order of the retained
+    /// [`char`]s. For UTF-16 backings a surrogate pair is kept or removed as a
+    /// whole.
+    ///
+    /// Invalid data is passed to `f` one wide character at a time as
+    /// [the replacement character (`U+FFFD`)][U+FFFD], and is kept unchanged
+    /// if retained.
+    ///
+    /// [U+FFFD]: char::REPLACEMENT_CHARACTER
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// let mut s: WCString<u16> = "f_o_🦀_b".parse().unwrap();
+    ///
+    /// s.retain(|c| c != '_');
+    ///
+    /// assert_eq!(s.as_wcstr().to_string_lossy(), "fo🦀b");
+    /// ```
+    ///
+    /// Invalid data can be removed by rejecting the replacement character:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// // a<invalid>b<nul>
+    /// let mut s = WCString::from_vec_with_nul(vec![0x0061u16, 0xDC00, 0x0062, 0x0000]).unwrap();
+    ///
+    /// s.retain(|c| c != char::REPLACEMENT_CHARACTER);
+    ///
+    /// assert_eq!(s.as_wcstr().to_slice_with_nul(), &[0x0061, 0x0062, 0x0000]);
+    /// ```
+    pub fn retain<F: FnMut(char) -> bool>(&mut self, mut f: F) {
+        let len = self.inner.len() - 1;
+
+        let mut read = 0;
+        let mut write = 0;
+        while read < len {
+            // SAFETY: The buffer from `read` onwards has not been written to, so
+            //         it is NUL-terminated with no interior NUL characters.
+            let rest = unsafe { WCStr::from_slice_with_nul_unchecked(&self.inner[read..]) };
+            let (c, n) = match rest.first_char() {
+                Some(Ok(c)) => (c, T::encode(c, &mut [T::NUL; 2]).len()),
+                _ => (char::REPLACEMENT_CHARACTER, 1),
+            };
+
+            if f(c) {
+                self.inner.copy_within(read..read + n, write);
+                write += n;
+            }
+            read += n;
+        }
+
+        self.inner.truncate(write);
+        self.inner.push(T::NUL);
+    }
+
+    /// Converts this string to its ASCII lower case equivalent in-place.
+    ///
+    /// ASCII letters 'A' to 'Z' are mapped to 'a' to 'z', but all other wide
+    /// characters, including surrogates, are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// let mut s: WCString<u16> = "GRÜßE, 🦀!".parse().unwrap();
+    /// s.make_ascii_lowercase();
+    ///
+    /// assert_eq!(s.as_wcstr().to_string_lossy(), "grÜße, 🦀!");
+    /// ```
+    #[inline]
+    pub fn make_ascii_lowercase(&mut self) {
+        for u in &mut self.inner {
+            *u = u.to_ascii_lowercase();
+        }
+    }
+
+    /// Converts this string to its ASCII upper case equivalent in-place.
+    ///
+    /// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z', but all other wide
+    /// characters, including surrogates, are unchanged.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// let mut s: WCString<u32> = "grüße, 🦀!".parse().unwrap();
+    /// s.make_ascii_uppercase();
+    ///
+    /// assert_eq!(s.as_wcstr().to_string_lossy(), "GRüßE, 🦀!");
+    /// ```
+    #[inline]
+    pub fn make_ascii_uppercase(&mut self) {
+        for u in &mut self.inner {
+            *u = u.to_ascii_uppercase();
+        }
+    }
+
+    /// Reverses the byte order of every wide character in this string
+    /// in-place.
+    ///
+    /// The NUL-terminator is unaffected, as is whether the string contains any
+    /// interior NUL characters. This is useful for producing a string for a
+    /// consumer of the opposite endianness.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// let mut s: WCString<u16> = "hi".parse().unwrap();
+    /// s.swap_bytes();
+    ///
+    /// assert_eq!(s.as_wcstr().to_slice_with_nul(), &[0x6800, 0x6900, 0x0000]);
+    /// ```
+    #[inline]
+    pub fn swap_bytes(&mut self) {
+        for u in &mut self.inner {
+            *u = u.swap_bytes();
+        }
+    }
+
+    /// Appends a given string slice onto the end of this `WCString`, returning
+    /// an error if `s` contains a NUL character.
+    ///
+    /// On error `self` is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::WCString;
+    ///
+    /// let mut s = WCString::<u32>::new();
+    ///
+    /// assert!(s.try_push_str("foo").is_ok());
+    /// assert_eq!(s.try_push_str("bar\0baz").unwrap_err().nul_position(), 3);
+    ///
+    /// assert_eq!(s.as_wcstr().to_slice(), &[0x0066, 0x006F, 0x006F]);
+    /// ```
+    pub fn try_push_str(&mut self, s: &str) -> Result<(), InteriorNulError> {
+        if let Some(pos) = s.find('\0') {
+            return Err(InteriorNulError::new(pos));
+        }
+
+        // A UTF-8 string is never shorter than its UTF-16 or UTF-32 encoding.
+        self.inner.reserve(s.len());
+        self.inner.pop();
+
+        let mut buf = [T::NUL; 2];
+        for c in s.chars() {
+            self.inner.extend_from_slice(T::encode(c, &mut buf));
+        }
+        self.inner.push(T::NUL);
+
+        Ok(())
+    }
+
+    /// Appends a wide string onto the end of this `WCString`.
+    ///
+    /// The capacity needed for `other` is reserved up front, so at most one
+    /// reallocation occurs.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use thicc::{wcstr, WCString};
+    ///
+    /// let mut s: WCString<u16> = "foo".parse().unwrap();
+    /// s.append(wcstr!(u16, "bar"));
+    ///
+    /// assert_eq!(s.as_wcstr().to_string_lossy(), "foobar");
+    /// ```
+    pub fn append(&mut self, other: &WCStr<T>) {
+        let other = other.to_slice_with_nul();
+
+        // The NUL-terminator of `self` is replaced by the one from `other`.
+        self.inner.reserve(other.len() - 1);
+        self.inner.pop();
+        self.inner.extend_from_slice(other);
+    }
+}
+
+impl<T: Wide> WCString<T> {
+    #[doc(hidden)]
+    pub fn __from_fmt(args: fmt::Arguments<'_>) -> Result<WCString<T>, InteriorNulError> {
+        use core::fmt::Write;
+
+        let mut buf = String::new();
+        // Writing into a `String` never fails.
+        let _ = buf.write_fmt(args);
+
+        WCString::from_str(&buf)
+    }
+}
+
+impl<T: Wide> Default for WCString<T> {
+    #[inline]
+    fn default() -> WCString<T> {
+        WCString::new()
+    }
+}
+
+/// Encodes a string slice as a `WCString`.
+///
+/// # Errors
+///
+/// Returns an [`InteriorNulError`] if the string slice contains a NUL
+/// character.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use thicc::WCString;
+///
+/// let s: WCString<u16> = "𝄞music".parse().unwrap();
+/// assert_eq!(s.as_wcstr().to_string_lossy(), "𝄞music");
+///
+/// let e = "a\0b".parse::<WCString<u32>>().err().unwrap();
+/// assert_eq!(e.nul_position(), 1);
+/// ```
+impl<T: Wide> FromStr for WCString<T> {
+    type Err = InteriorNulError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<WCString<T>, InteriorNulError> {
+        let mut string = WCString::new();
+        string.try_push_str(s)?;
+        Ok(string)
+    }
+}
+
+/// Encodes a [`char`] as a `WCString`.
+///
+/// For UTF-16 backings a [`char`] outside of the Basic Multilingual Plane is
+/// encoded as a surrogate pair.
+///
+/// # Errors
+///
+/// Returns an [`InteriorNulError`] if the [`char`] is the NUL character.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use thicc::WCString;
+///
+/// let s = WCString::<u16>::try_from('a').unwrap();
+/// assert_eq!(s.as_wcstr().to_slice(), &[0x0061]);
+///
+/// let s = WCString::<u16>::try_from('🎉').unwrap();
+/// assert_eq!(s.as_wcstr().to_slice(), &[0xD83C, 0xDF89]);
+///
+/// let s = WCString::<u32>::try_from('🎉').unwrap();
+/// assert_eq!(s.as_wcstr().to_slice(), &[0x1F389]);
+///
+/// assert!(WCString::<u16>::try_from('\0').is_err());
+/// ```
+impl<T: Wide> TryFrom<char> for WCString<T> {
+    type Error = InteriorNulError;
+
+    #[inline]
+    fn try_from(c: char) -> Result<WCString<T>, InteriorNulError> {
+        if c == '\0' {
+            return Err(InteriorNulError::new(0));
+        }
+
+        let mut string = WCString::new();
+        string.push_char(c);
+        Ok(string)
+    }
+}
+
+/// Encodes each [`char`] of an iterator onto the end of a `WCString`, as with
+/// [`WCString::push_char`].
+///
+/// # Panics
+///
+/// Panics if the iterator yields the NUL character.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use thicc::WCString;
+///
+/// let mut s: WCString<u16> = "ab".parse().unwrap();
+/// s.extend("c🦀".chars());
+///
+/// assert_eq!(s.as_wcstr().to_string_lossy(), "abc🦀");
+/// ```
+impl<T: Wide> Extend<char> for WCString<T> {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.inner.reserve(iter.size_hint().0);
+        iter.for_each(|c| self.push_char(c));
+    }
+}
+
+/// Appends each wide string of an iterator onto the end of a `WCString`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use thicc::{wcstr, WCString};
+///
+/// let mut s = WCString::<u32>::new();
+/// s.extend(vec![wcstr!(u32, "foo"), wcstr!(u32, "/"), wcstr!(u32, "bar")]);
+///
+/// assert_eq!(s.as_wcstr().to_string_lossy(), "foo/bar");
+/// ```
+///
+/// The NUL-terminator stays in place while the iterator runs, so the string
+/// remains valid if the iterator panics:
+///
+/// ```
+/// use std::panic::{self, AssertUnwindSafe};
+///
+/// use thicc::{wcstr, WCString};
+///
+/// let mut s: WCString<u32> = "ab".parse().unwrap();
+/// let iter = vec![Some(wcstr!(u32, "x")), None]
+///     .into_iter()
+///     .map(|s| s.expect("iterator panicked"));
+///
+/// let result = panic::catch_unwind(AssertUnwindSafe(|| s.extend(iter)));
+///
+/// assert!(result.is_err());
+/// assert!(s.as_wcstr() == wcstr!(u32, "abx"));
+/// assert_eq!(s.as_wcstr().to_slice_with_nul(), &[0x61, 0x62, 0x78, 0]);
+/// ```
+impl<'a, T: Wide> Extend<&'a WCStr<T>> for WCString<T> {
+    fn extend<I: IntoIterator<Item = &'a WCStr<T>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|s| self.append(s));
+    }
+}
+
+/// Allows formatted text to be written into a `WCString`.
+///
+/// The text is encoded into the backing type of the wide string, as with
+/// [`WCString::try_push_str`].
+///
+/// # Errors
+///
+/// Writing text that contains a NUL character fails with [`fmt::Error`],
+/// leaving the wide string unchanged by that write.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use std::fmt::Write;
+/// use thicc::WCString;
+///
+/// let mut s = WCString::<u16>::new();
+/// write!(s, "{}/{}", "foo", 42).unwrap();
+///
+/// assert_eq!(s.to_string_lossy(), "foo/42");
+/// assert!(write!(s, "{}", '\0').is_err());
+/// ```
+impl<T: Wide> fmt::Write for WCString<T> {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_push_str(s).map_err(|_| fmt::Error)
+    }
+
+    #[inline]
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        if c == '\0' {
+            return Err(fmt::Error);
+        }
+        self.push_char(c);
+        Ok(())
+    }
+}
+
+/// Collects an iterator of [`char`]s into a `WCString`.
+///
+/// # Panics
+///
+/// Panics if the iterator yields the NUL character.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use thicc::WCString;
+///
+/// let s: WCString<u16> = "🦀rust".chars().rev().collect();
+///
+/// assert_eq!(s.as_wcstr().to_string_lossy(), "tsur🦀");
+/// ```
+impl<T: Wide> FromIterator<char> for WCString<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> WCString<T> {
+        let mut string = WCString::new();
+        string.extend(iter);
+        string
+    }
+}
+
+impl<T: Wide> PartialEq for WCString<T> {
+    #[inline]
+    fn eq(&self, other: &WCString<T>) -> bool {
+        self.as_wcstr() == other.as_wcstr()
+    }
+}
+
+impl<T: Wide> Eq for WCString<T> {}
+
+/// Compares an owned wide string with a borrowed one.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use thicc::{wcstr, WCString};
+///
+/// let s: WCString<u16> = "x".parse().unwrap();
+///
+/// assert!(s == *wcstr!(u16, "x"));
+/// assert!(s != *wcstr!(u16, "y"));
+/// ```
+impl<T: Wide> PartialEq<WCStr<T>> for WCString<T> {
+    #[inline]
+    fn eq(&self, other: &WCStr<T>) -> bool {
+        self.as_wcstr() == other
+    }
+}
+
+/// Compares an owned wide string with a borrowed one.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use thicc::{wcstr, WCString};
+///
+/// let s: WCString<u16> = "x".parse().unwrap();
+///
+/// assert!(s == wcstr!(u16, "x"));
+/// assert!(s != wcstr!(u16, "y"));
+/// ```
+impl<T: Wide> PartialEq<&WCStr<T>> for WCString<T> {
+    #[inline]
+    fn eq(&self, other: &&WCStr<T>) -> bool {
+        self.as_wcstr() == *other
+    }
+}
+
+/// Compares a borrowed wide string with an owned one.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use thicc::{wcstr, WCString};
+///
+/// let s: WCString<u16> = "x".parse().unwrap();
+///
+/// assert!(*wcstr!(u16, "x") == s);
+/// assert!(*wcstr!(u16, "y") != s);
+/// ```
+impl<T: Wide> PartialEq<WCString<T>> for WCStr<T> {
+    #[inline]
+    fn eq(&self, other: &WCString<T>) -> bool {
+        self == other.as_wcstr()
+    }
+}
+
+/// Compares a borrowed wide string with an owned one.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use thicc::{wcstr, WCString};
+///
+/// let s: WCString<u16> = "x".parse().unwrap();
+///
+/// assert!(wcstr!(u16, "x") == s);
+/// assert!(wcstr!(u16, "y") != s);
+/// ```
+impl<T: Wide> PartialEq<WCString<T>> for &WCStr<T> {
+    #[inline]
+    fn eq(&self, other: &WCString<T>) -> bool {
+        *self == other.as_wcstr()
+    }
+}
+
+impl<T: Wide> PartialOrd for WCString<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &WCString<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Wide> Ord for WCString<T> {
+    #[inline]
+    fn cmp(&self, other: &WCString<T>) -> Ordering {
+        self.as_wcstr().cmp(other.as_wcstr())
+    }
+}
+
+/// Hashes a `WCString` identically to the [`WCStr`] it derefs to.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use std::collections::hash_map::DefaultHasher;
+/// use std::hash::{Hash, Hasher};
+/// use thicc::{wcstr, WCStr, WCString};
+///
+/// fn hash<H: Hash + ?Sized>(value: &H) -> u64 {
+///     let mut hasher = DefaultHasher::new();
+///     value.hash(&mut hasher);
+///     hasher.finish()
+/// }
+///
+/// let borrowed: &WCStr<u16> = wcstr!(u16, "key");
+/// let owned: WCString<u16> = "key".parse().unwrap();
+///
+/// assert_eq!(hash(borrowed), hash(&owned));
+/// assert_eq!(hash(borrowed), hash(owned.as_wcstr()));
+/// ```
+impl<T: Wide> Hash for WCString<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_wide_str(self.as_wcstr(), state);
+    }
+}
+
+impl<T: Wide> AsRef<WCStr<T>> for WCString<T> {
+    #[inline]
+    fn as_ref(&self) -> &WCStr<T> {
+        self.as_wcstr()
+    }
+}
+
+/// Dereferences a `WCString` to a [`WCStr`], so that all of the methods of a
+/// borrowed wide string can be called on an owned one.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use thicc::WCString;
+///
+/// let s: WCString<u16> = "🦀rust".parse().unwrap();
+///
+/// assert_eq!(s.len(), 6);
+/// assert_eq!(s.char_count(), 5);
+/// assert_eq!(s.to_string_lossy(), "🦀rust");
+/// ```
+impl<T: Wide> Deref for WCString<T> {
+    type Target = WCStr<T>;
+
+    #[inline]
+    fn deref(&self) -> &WCStr<T> {
+        self.as_wcstr()
+    }
+}
+
+/// Borrows a `WCString` as a [`WCStr`].
+///
+/// Equality, ordering and hashing of a `WCString` are the same as for the
+/// borrowed [`WCStr`], so a `WCString` key can be looked up by `&WCStr`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// use std::collections::HashMap;
+/// use thicc::{wcstr, WCString};
+/// use wchar::wchz;
+///
+/// let key = WCString::from_vec_with_nul(wchz!(u16, "key").to_vec()).unwrap();
+///
+/// let mut map = HashMap::new();
+/// map.insert(key, 42);
+///
+/// assert_eq!(map.get(wcstr!(u16, "key")), Some(&42));
+/// assert_eq!(map.get(wcstr!(u16, "other")), None);
+/// ```
+impl<T: Wide> Borrow<WCStr<T>> for WCString<T> {
+    #[inline]
+    fn borrow(&self) -> &WCStr<T> {
+        self.as_wcstr()
+    }
+}
+
+/// The widest [`char`] that a [`WCString`] is expected to hold, used to reserve
+/// capacity.
+///
+/// This is used by the [`WCString::with_exact_capacity_for`] method.
+/// See its documentation for more.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CharWidth {
+    /// Every [`char`] is in the Basic Multilingual Plane, and so is encoded as
+    /// a single wide character for both UTF-16 and UTF-32.
+    Bmp,
+    /// [`char`]s may be outside of the Basic Multilingual Plane, and so may be
+    /// encoded as a surrogate pair for UTF-16.
+    Supplementary,
+}
+
+/// An error indicating that an interior NUL character was found.
+///
+/// This error is created by the [`WCString::try_push_str`] and
+/// [`WCString::try_from_units`] methods, and by the [`FromStr`] and
+/// [`TryFrom<char>`] implementations for [`WCString`].
+/// See their documentation for more.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct InteriorNulError {
+    pos: usize,
+}
+
+impl InteriorNulError {
+    const fn new(pos: usize) -> InteriorNulError {
+        InteriorNulError { pos }
+    }
+
+    /// Returns the position of the NUL character in the input that caused
+    /// this error.
+    #[inline]
+    pub fn nul_position(&self) -> usize {
+        self.pos
+    }
+}
+
+/// An error indicating that a byte slice could not be read as a wide string.
+///
+/// This error is created by the [`WCString::from_bytes_le`] and
+/// [`WCString::from_bytes_be`] methods. See their documentation for more.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FromBytesError {
+    kind: FromBytesErrorKind,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum FromBytesErrorKind {
+    InteriorNul(usize),
+    InvalidLength(usize),
+}
+
+impl FromBytesError {
+    const fn interior_nul(pos: usize) -> FromBytesError {
+        FromBytesError {
+            kind: FromBytesErrorKind::InteriorNul(pos),
+        }
+    }
+
+    const fn invalid_length(len: usize) -> FromBytesError {
+        FromBytesError {
+            kind: FromBytesErrorKind::InvalidLength(len),
+        }
+    }
+}
+
+/// An error indicating that a NUL character was not in the expected position.
+///
+/// The vector used to create a [`WCString`] must have one and only one NUL
+/// character, positioned at the end.
+///
+/// This error is created by the [`WCString::from_vec_with_nul`] method.
+/// See its documentation for more.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FromVecWithNulError<T: Wide> {
+    error: FromSliceWithNulError,
+    vec: Vec<T>,
+}
+
+impl<T: Wide> FromVecWithNulError<T> {
+    /// Returns a slice of the wide characters that were attempted to convert
+    /// to a [`WCString`].
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.vec
+    }
+
+    /// Returns the vector of wide characters that were attempted to convert
+    /// to a [`WCString`], without copying.
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        self.vec
+    }
+}